@@ -0,0 +1,161 @@
+use tower_lsp::lsp_types::Color;
+
+/// Named colors common enough to show up in hand-written stylesheets.
+const NAMED: &[(&str, &str)] = &[
+    ("black", "#000000"),
+    ("white", "#ffffff"),
+    ("red", "#ff0000"),
+    ("green", "#008000"),
+    ("blue", "#0000ff"),
+    ("yellow", "#ffff00"),
+    ("orange", "#ffa500"),
+    ("purple", "#800080"),
+    ("pink", "#ffc0cb"),
+    ("gray", "#808080"),
+    ("grey", "#808080"),
+    ("silver", "#c0c0c0"),
+    ("maroon", "#800000"),
+    ("navy", "#000080"),
+    ("teal", "#008080"),
+    ("olive", "#808000"),
+    ("lime", "#00ff00"),
+    ("aqua", "#00ffff"),
+    ("cyan", "#00ffff"),
+    ("fuchsia", "#ff00ff"),
+    ("magenta", "#ff00ff"),
+    ("transparent", "#00000000"),
+];
+
+/// Parses a CSS color value: `#rgb[a]`, `#rrggbb[aa]`, `rgb()/rgba()`,
+/// `hsl()/hsla()` or a common named color. Anything else (variables,
+/// gradients, `currentColor`) yields None.
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim().trim_end_matches("!important").trim();
+    let lower = value.to_ascii_lowercase();
+    if let Some(hex) = lower.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    if let Some(&(_, hex)) = NAMED.iter().find(|(name, _)| *name == lower) {
+        return parse_hex(&hex[1..]);
+    }
+    let (func, args) = lower.split_once('(')?;
+    let args: Vec<&str> = args
+        .strip_suffix(')')?
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|a| !a.is_empty())
+        .collect();
+    if args.len() < 3 {
+        return None;
+    }
+    let alpha = match args.get(3) {
+        Some(a) => parse_fraction(a, 1.0)?,
+        None => 1.0,
+    };
+    match func.trim() {
+        "rgb" | "rgba" => Some(Color {
+            red: parse_fraction(args[0], 255.0)?,
+            green: parse_fraction(args[1], 255.0)?,
+            blue: parse_fraction(args[2], 255.0)?,
+            alpha,
+        }),
+        "hsl" | "hsla" => {
+            let hue = args[0].trim_end_matches("deg").parse::<f32>().ok()?;
+            let (red, green, blue) = hsl_to_rgb(
+                hue,
+                parse_fraction(args[1], 100.0)?,
+                parse_fraction(args[2], 100.0)?,
+            );
+            Some(Color {
+                red,
+                green,
+                blue,
+                alpha,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    let digits: Vec<f32> = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as f32))
+        .collect::<Option<_>>()?;
+    let channels: Vec<f32> = match digits.len() {
+        3 | 4 => digits.iter().map(|d| d * 17.0 / 255.0).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|p| (p[0] * 16.0 + p[1]) / 255.0)
+            .collect(),
+        _ => return None,
+    };
+    Some(Color {
+        red: channels[0],
+        green: channels[1],
+        blue: channels[2],
+        alpha: channels.get(3).copied().unwrap_or(1.0),
+    })
+}
+
+/// `50%` → 0.5; a bare number is divided by `scale`.
+fn parse_fraction(arg: &str, scale: f32) -> Option<f32> {
+    let value = match arg.strip_suffix('%') {
+        Some(p) => p.parse::<f32>().ok()? / 100.0,
+        None => arg.parse::<f32>().ok()? / scale,
+    };
+    Some(value.clamp(0.0, 1.0))
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (f32, f32, f32) {
+    let c = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = lightness - c / 2.0;
+    (r + m, g + m, b + m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The color as 0–255 channels, for exact comparisons.
+    fn rgba(value: &str) -> Option<[u8; 4]> {
+        let c = parse_color(value)?;
+        Some([c.red, c.green, c.blue, c.alpha].map(|v| (v * 255.0).round() as u8))
+    }
+
+    #[test]
+    fn hex_and_named_colors() {
+        assert_eq!(rgba("#0d6efd"), Some([13, 110, 253, 255]));
+        assert_eq!(rgba("#F00"), Some([255, 0, 0, 255]));
+        assert_eq!(rgba("#0000ff80"), Some([0, 0, 255, 128]));
+        assert_eq!(rgba("navy !important"), Some([0, 0, 128, 255]));
+        assert_eq!(rgba("transparent"), Some([0, 0, 0, 0]));
+        assert_eq!(rgba("#12345"), None);
+    }
+
+    #[test]
+    fn functional_colors() {
+        assert_eq!(rgba("rgb(255, 128, 0)"), Some([255, 128, 0, 255]));
+        assert_eq!(rgba("rgba(0,0,0,.5)"), Some([0, 0, 0, 128]));
+        assert_eq!(rgba("rgb(0 0 0 / 25%)"), Some([0, 0, 0, 64]));
+        assert_eq!(rgba("hsl(120deg, 100%, 50%)"), Some([0, 255, 0, 255]));
+        assert_eq!(rgba("hsla(240, 100%, 50%, 1)"), Some([0, 0, 255, 255]));
+    }
+
+    #[test]
+    fn values_that_are_not_plain_colors() {
+        let values = ["var(--bs-primary)", "currentColor", "linear-gradient(red, blue)", "inherit"];
+        for value in values {
+            assert_eq!(rgba(value), None, "{value}");
+        }
+    }
+}
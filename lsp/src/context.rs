@@ -1,13 +1,22 @@
 use regex::Regex;
+use std::sync::LazyLock;
 use tower_lsp::lsp_types::Position;
 
+use crate::settings::Settings;
+
+// Compiled once: these run per token on every keystroke.
+static NAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^-?[a-zA-Z_][a-zA-Z0-9_-]*$").unwrap());
+static WORD_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\S+").unwrap());
+static DIRECTIVE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|\s)(?:class:([\w-]+)|\[class\.([\w-]*))").unwrap());
+
 /// Converts an LSP Position (line + UTF-16 char offset) to a byte offset.
 pub fn position_to_offset(text: &str, pos: Position) -> Option<usize> {
-    let mut current_line = 0u32;
     let mut offset = 0usize;
 
-    for line in text.split('\n') {
-        if current_line == pos.line {
+    for (current_line, line) in text.split('\n').enumerate() {
+        if current_line as u32 == pos.line {
             let mut utf16_offset = 0u32;
             for (byte_idx, ch) in line.char_indices() {
                 if utf16_offset == pos.character {
@@ -25,13 +34,72 @@ pub fn position_to_offset(text: &str, pos: Position) -> Option<usize> {
             return None;
         }
         offset += line.len() + 1; // +1 for '\n'
-        current_line += 1;
     }
     None
 }
 
-/// Returns true if the cursor at `pos` is inside a class="..." or class='...' attribute value.
-pub fn is_in_class_attribute(text: &str, pos: Position) -> bool {
+/// Converts byte offsets back to LSP Positions, for files with many lookups.
+pub struct LineIndex<'a> {
+    text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        LineIndex { text, line_starts }
+    }
+
+    pub fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&s| s <= offset) - 1;
+        let start = self.line_starts[line];
+        let character = self.text[start..offset]
+            .chars()
+            .map(|c| c.len_utf16() as u32)
+            .sum();
+        Position::new(line as u32, character)
+    }
+}
+
+/// Helpers whose object arguments configure variants rather than toggle
+/// classes: keys are variant names, and only the strings under `variants`
+/// and the `class` of `compoundVariants` entries are classes.
+const VARIANT_FUNCTIONS: &[&str] = &["cva"];
+
+/// Angular's class directive, bound like a class attribute:
+/// `ngClass="a b"` or `[ngClass]="{ 'is-open': open }"`.
+const NG_CLASS: &str = "ngClass";
+
+/// Twig, Jinja, Blade and Angular tags: (open, close, whether it prints).
+const TEMPLATE_TAGS: &[(&str, &str, bool)] =
+    &[("{{", "}}", true), ("{%", "%}", false), ("{#", "#}", false)];
+
+/// Matches the opening of a class attribute value (`class="`, `className='`,
+/// `class=\"` inside a PHP or JS string, an expression container
+/// `className={`, a Vue binding `:class="` or an Angular binding
+/// `[ngClass]="`) for any of `attributes`. Group 1 or 2 is set for a binding.
+fn attribute_open_re(attributes: &[String]) -> Regex {
+    let names: Vec<String> = attributes
+        .iter()
+        .map(String::as_str)
+        .chain([NG_CLASS])
+        .map(regex::escape)
+        .collect();
+    let names = names.join("|");
+    Regex::new(&format!(
+        r#"(?:(\[)(?:{names})\]|((?:v-bind)?:)?\b(?:{names}))\s*=\s*\\?["'{{]"#
+    ))
+    .unwrap()
+}
+
+/// Returns true if the cursor at `pos` is somewhere a class list is written:
+/// a class attribute value (`class="..."`, `className={active ? "a" : "b"}`,
+/// `:class="['a', b]"`, `[ngClass]="{ 'is-open': open }"`), a Svelte
+/// `class:name` directive or Angular `[class.name]` binding, or a string
+/// literal or unquoted object key passed to a class helper (`clsx("...")`,
+/// `clsx({ active: on })`).
+pub fn is_in_class_context(text: &str, pos: Position, settings: &Settings) -> bool {
     let cursor = match position_to_offset(text, pos) {
         Some(o) => o,
         None => {
@@ -43,36 +111,799 @@ pub fn is_in_class_attribute(text: &str, pos: Position) -> bool {
         }
     };
 
-    let before = &text[..cursor];
+    let result = class_values(text, settings)
+        .iter()
+        .any(|v| v.start <= cursor && cursor <= v.end);
+
+    eprintln!("[css-class-lsp] is_in_class_context={result} cursor={cursor}");
+    result
+}
+
+/// A class name written in markup, with its byte span.
+#[derive(Debug, Clone)]
+pub struct ClassToken {
+    pub name: String,
+    pub start: usize,
+    pub end: usize,
+    /// Set if written as an unquoted object key (`clsx({ active: on })`):
+    /// the quote to wrap it in when renamed to a name with a `-`.
+    pub bare_key: Option<char>,
+}
+
+/// The byte span of a class list: an attribute value, a string literal (or
+/// the text of a template literal) in a JSX expression or helper call, or a
+/// single unquoted object key.
+#[derive(Debug, Clone, Copy)]
+pub struct ClassValue {
+    pub start: usize,
+    pub end: usize,
+    pub bare_key: Option<char>,
+}
+
+/// What a scan of a document collects.
+#[derive(Default)]
+struct Found {
+    values: Vec<ClassValue>,
+    /// Byte spans of the template code embedded in class lists (`<?= ... ?>`,
+    /// `{{ ... }}`, `${...}`), which only the string literals of are read.
+    expressions: Vec<(usize, usize)>,
+}
+
+fn scan(text: &str, settings: &Settings) -> Found {
+    let mut found = Found::default();
+    class_attribute_values(text, &settings.class_attributes, &mut found);
+    class_helper_values(text, &settings.class_functions, &mut found);
+    found.values.extend(class_directive_values(text));
+    found.values.sort_by_key(|v| (v.start, v.end));
+    // A helper called inside a JSX expression is found by both scans
+    found.values.dedup_by_key(|v| (v.start, v.end));
+    found.expressions.sort();
+    found.expressions.dedup();
+    found
+}
+
+/// Every class list in `text`, in document order.
+pub fn class_values(text: &str, settings: &Settings) -> Vec<ClassValue> {
+    scan(text, settings).values
+}
+
+/// The byte spans of the template expressions inside class lists
+/// (`class="item {{ active }}"`, `<?= ... ?>`, `${...}`), in document order.
+/// Class names in them can't be edited safely, except string literals,
+/// which [`class_tokens`] returns.
+pub fn class_expressions(text: &str, settings: &Settings) -> Vec<(usize, usize)> {
+    scan(text, settings).expressions
+}
+
+/// The class lists of every class attribute: quoted values
+/// (`class="..."`, split around Svelte's `{...}` expressions, template tags,
+/// PHP blocks and string concatenations), and the
+/// strings, object keys and template literal text of expression containers
+/// (`className={`card ${on ? "active" : ""}`}`) and Vue bindings
+/// (`:class="{ active: on }"`).
+fn class_attribute_values(text: &str, attributes: &[String], out: &mut Found) {
+    let open_re = attribute_open_re(attributes);
+    let mut scanned_to = 0;
+    for c in open_re.captures_iter(text) {
+        let m = c.get(0).unwrap();
+        if m.start() < scanned_to {
+            continue;
+        }
+        let open = text[m.end() - 1..m.end()].chars().next().unwrap();
+        if open == '{' {
+            scanned_to = scan_expression(text, m.end(), Mode::Binding, out);
+            continue;
+        }
+        // `echo "<a class=\"btn\">"`
+        let close = if text[..m.end() - 1].ends_with('\\') {
+            format!("\\{open}")
+        } else {
+            open.to_string()
+        };
+        let end = text[m.end()..]
+            .find(&close)
+            .map_or(text.len(), |i| m.end() + i);
+        let value = &text[m.end()..end];
+        scanned_to = if c.get(1).is_some() || c.get(2).is_some() {
+            let first = out.values.len();
+            scan_expression(&text[..end], m.end(), Mode::Binding, out);
+            // Quoting a key must not close the attribute
+            if open == '"' {
+                for v in &mut out.values[first..] {
+                    v.bare_key = v.bare_key.map(|_| '\'');
+                }
+            }
+            end
+        } else if value.contains('{')
+            && !["{{", "{%", "{#", "<?"].iter().any(|t| value.contains(t))
+        {
+            scan_interpolated(text, m.end() - 1, "{", true, out)
+        } else {
+            scan_quoted(text, m.end(), &close, out)
+        };
+    }
+}
+
+/// The class names of Svelte `class:name={on}` directives and Angular
+/// `[class.name]="on"` bindings.
+fn class_directive_values(text: &str) -> Vec<ClassValue> {
+    DIRECTIVE_RE
+        .captures_iter(text)
+        .map(|c| {
+            let name = c.get(1).or(c.get(2)).unwrap();
+            ClassValue { start: name.start(), end: name.end(), bare_key: None }
+        })
+        .collect()
+}
+
+/// The class lists inside calls to the helper `functions`: string literals
+/// (`clsx("btn", on && "active")`) and object keys, quoted or not
+/// (`classNames({ active: on, "btn-lg": big })`). Object values are the
+/// conditions, so they are skipped.
+fn class_helper_values(text: &str, functions: &[String], out: &mut Found) {
+    if functions.is_empty() {
+        return;
+    }
+    let names: Vec<String> = functions.iter().map(|f| regex::escape(f)).collect();
+    let call_re = Regex::new(&format!(r"(?:^|[^\w$])({})\s*\(", names.join("|"))).unwrap();
+
+    let mut scanned_to = 0;
+    for c in call_re.captures_iter(text) {
+        let open = c.get(0).unwrap().end();
+        // A helper nested in another helper's arguments was already scanned
+        if open <= scanned_to {
+            continue;
+        }
+        let mode = if VARIANT_FUNCTIONS.contains(&&c[1]) { Mode::Variants } else { Mode::Helper };
+        scanned_to = scan_expression(text, open, mode, out);
+    }
+}
+
+/// How the strings of a scanned expression are read.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Arguments of a class helper: strings and object keys are classes,
+    /// object values are conditions.
+    Helper,
+    /// Arguments of a variant helper such as `cva` (see [`VARIANT_FUNCTIONS`]).
+    Variants,
+    /// A bound class attribute (`className={...}`, Svelte `class={...}`, Vue
+    /// `:class="..."`): strings it evaluates to, array items and object keys
+    /// are classes, but not the arguments of calls.
+    Binding,
+    /// A template literal interpolation: only strings it evaluates to, such
+    /// as ternary branches, are classes.
+    Expression,
+}
+
+/// An open bracket inside a scanned expression.
+enum Frame {
+    /// `(...)` used for grouping, or `[...]`.
+    Group,
+    /// The arguments of a call, `f(...)`.
+    Call,
+    /// `{...}`, with the key of the current entry and whether the scan has
+    /// passed its `:`.
+    Object { key: Option<String>, in_value: bool },
+}
+
+/// Walks an expression from just after its opening `(` or `{`, collecting
+/// class values, and returns the offset just past the matching close.
+fn scan_expression(text: &str, start: usize, mode: Mode, out: &mut Found) -> usize {
+    let bytes = text.as_bytes();
+    let mut stack = vec![Frame::Group];
+    let mut i = start;
+    while i < bytes.len() {
+        let in_key = matches!(stack.last(), Some(Frame::Object { in_value: false, .. }));
+        match bytes[i] {
+            b'"' | b'\'' => {
+                let end = string_end(text, i);
+                if in_key {
+                    set_key(&mut stack, &text[i + 1..end]);
+                }
+                if holds_classes(&stack, in_key, mode) && !is_compared(text, i, end) {
+                    out.values.push(ClassValue { start: i + 1, end, bare_key: None });
+                }
+                i = end + 1;
+                continue;
+            }
+            b'`' => {
+                let classes = holds_classes(&stack, in_key, mode);
+                i = scan_interpolated(text, i, "${", classes, out);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = text[i..].find('\n').map_or(bytes.len(), |n| i + n);
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = text[i + 2..].find("*/").map_or(bytes.len(), |n| i + 2 + n + 2);
+                continue;
+            }
+            b'(' => {
+                let callee = text[..i].trim_end().bytes().next_back();
+                let is_call = callee.is_some_and(|c| {
+                    c.is_ascii_alphanumeric() || matches!(c, b'_' | b'$' | b')' | b']')
+                });
+                stack.push(if is_call { Frame::Call } else { Frame::Group });
+            }
+            b'[' => stack.push(Frame::Group),
+            b'{' => stack.push(Frame::Object { key: None, in_value: false }),
+            b')' | b']' | b'}' => {
+                stack.pop();
+                if stack.is_empty() {
+                    return i + 1;
+                }
+            }
+            b':' => {
+                if let Some(Frame::Object { in_value, .. }) = stack.last_mut() {
+                    *in_value = true;
+                }
+            }
+            b',' => {
+                if let Some(Frame::Object { key, in_value }) = stack.last_mut() {
+                    *key = None;
+                    *in_value = false;
+                }
+            }
+            c if c.is_ascii_alphabetic() || c == b'_' || c == b'$' => {
+                let end = text[i..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+                    .map_or(bytes.len(), |n| i + n);
+                // `{ active: on }` or shorthand `{ active }`, but not `...rest`
+                // or a method
+                let is_key = in_key
+                    && (i == 0 || bytes[i - 1] != b'.')
+                    && matches!(text[end..].trim_start().bytes().next(), Some(b':' | b',' | b'}'));
+                if is_key {
+                    set_key(&mut stack, &text[i..end]);
+                    if holds_classes(&stack, true, mode) {
+                        out.values.push(ClassValue { start: i, end, bare_key: Some('"') });
+                    }
+                }
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+fn set_key(stack: &mut [Frame], name: &str) {
+    if let Some(Frame::Object { key, .. }) = stack.last_mut() {
+        *key = Some(name.to_string());
+    }
+}
+
+/// Whether a string or key at the top of `stack` is a class list.
+fn holds_classes(stack: &[Frame], is_key: bool, mode: Mode) -> bool {
+    match mode {
+        // Entry values are the conditions
+        Mode::Helper => {
+            return !stack
+                .iter()
+                .any(|f| matches!(f, Frame::Object { in_value: true, .. }));
+        }
+        // Helpers among the calls are scanned on their own
+        Mode::Binding => {
+            return !stack
+                .iter()
+                .any(|f| matches!(f, Frame::Call | Frame::Object { in_value: true, .. }));
+        }
+        Mode::Expression => return !is_key && stack.iter().all(|f| matches!(f, Frame::Group)),
+        Mode::Variants if is_key => return false,
+        Mode::Variants => {}
+    }
+    let path: Vec<&str> = stack
+        .iter()
+        .filter_map(|f| match f {
+            Frame::Object { key, .. } => Some(key.as_deref().unwrap_or("")),
+            Frame::Group | Frame::Call => None,
+        })
+        .collect();
+    match path.as_slice() {
+        [] => true,
+        // variants: { intent: { primary: "..." } }
+        ["variants", _, _, ..] => true,
+        // compoundVariants: [{ intent: "primary", class: "..." }]
+        ["compoundVariants", .., last] => matches!(*last, "class" | "className"),
+        _ => false,
+    }
+}
+
+/// Offset of the quote closing the string literal opened at `start`, or of
+/// the end of the line if unterminated.
+fn string_end(text: &str, start: usize) -> usize {
+    let bytes = text.as_bytes();
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'\n' => return i,
+            c if c == quote => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// True if the string literal from `start` to `end` (its quotes) is compared
+/// against, as in `variant === "primary" ? ... : ...`, rather than used.
+fn is_compared(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].trim_end();
+    let after = text.get(end + 1..).unwrap_or("").trim_start();
+    before.ends_with("==")
+        || before.ends_with("!=")
+        || after.starts_with("==")
+        || after.starts_with("!=")
+}
+
+/// Walks a template literal, or a Svelte attribute value, whose quote is at
+/// `start` and returns the offset just past the closing quote. If it
+/// `classes`, the text around its interpolations (`opener` is `${` or `{`)
+/// is collected, and each interpolation is scanned as an expression
+/// (`${active ? "card-active" : ""}`).
+fn scan_interpolated(
+    text: &str,
+    start: usize,
+    opener: &str,
+    classes: bool,
+    out: &mut Found,
+) -> usize {
+    let bytes = text.as_bytes();
+    let quote = bytes[start];
+    let mut chunk = start + 1;
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if quote == b'`' => i += 1,
+            c if c == quote => {
+                if classes {
+                    push_chunk(text, chunk, i, chunk > start + 1, false, out);
+                }
+                return i + 1;
+            }
+            _ if bytes[i..].starts_with(opener.as_bytes()) => {
+                let open = i + opener.len();
+                if classes {
+                    push_chunk(text, chunk, i, chunk > start + 1, true, out);
+                    let end = scan_expression(text, open, Mode::Expression, out);
+                    out.expressions.push((i, end));
+                    i = end;
+                } else {
+                    i = scan_expression(text, open, Mode::Expression, &mut Found::default());
+                }
+                chunk = i;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    if classes {
+        push_chunk(text, chunk, bytes.len(), chunk > start + 1, false, out);
+    }
+    bytes.len()
+}
 
-    // Match both class="..." (HTML/PHP) and className="..." (JSX/TSX)
-    let open_re = Regex::new(r#"\bclass(?:Name)?\s*=\s*["']"#).unwrap();
-    let last_match = open_re.find_iter(before).last();
+/// Walks a plain quoted attribute value from `start` to its `close` and
+/// returns the offset of the close. The value is split around the code
+/// embedded in it: template tags (`class="item {{ active }}"`), which are
+/// left alone, and PHP blocks (`class="btn <?= $on ? 'active' : '' ?>"`) and
+/// the concatenations of the string the markup is written in
+/// (`echo '<a class="btn ' . $size . '">'`), whose string literals are
+/// scanned as expressions.
+fn scan_quoted(text: &str, start: usize, close: &str, out: &mut Found) -> usize {
+    let bytes = text.as_bytes();
+    // The quote of a string literal holding the markup
+    let outer = if close == "\"" { b'\'' } else { b'"' };
+    let (mut chunk, mut glued) = (start, false);
+    let mut i = start;
+    while i < bytes.len() && !bytes[i..].starts_with(close.as_bytes()) {
+        // (code start, code end, where the value resumes, whether the code
+        // prints into the value, whether its strings are read)
+        let tag = TEMPLATE_TAGS.iter().find(|(open, ..)| bytes[i..].starts_with(open.as_bytes()));
+        let code = if let Some((open, close, prints)) = tag {
+            let end = text[i..].find(close).map_or(bytes.len(), |n| i + n);
+            Some((i + open.len(), end, (end + close.len()).min(bytes.len()), *prints, false))
+        } else if bytes[i..].starts_with(b"<?") {
+            let end = text[i..].find("?>").map_or(bytes.len(), |n| i + n);
+            let opener = ["<?php", "<?=", "<?"].iter().find(|t| text[i..].starts_with(*t));
+            let from = i + opener.unwrap().len();
+            let prints = text[i..].starts_with("<?=")
+                || text[from..end]
+                    .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .any(|w| w == "echo" || w == "print");
+            Some((from, end, (end + 2).min(bytes.len()), prints, true))
+        } else if bytes[i] == outer {
+            concat_end(text, i).map(|reopen| (i + 1, reopen, reopen + 1, true, true))
+        } else {
+            None
+        };
+        if let Some((from, to, resume, prints, strings)) = code {
+            push_chunk(text, chunk, i, glued, prints, out);
+            if strings {
+                scan_expression(&text[..to], from, Mode::Expression, out);
+            }
+            out.expressions.push((i, resume));
+            (chunk, glued, i) = (resume, prints, resume);
+            continue;
+        }
+        i += 1;
+    }
+    push_chunk(text, chunk, i, glued, false, out);
+    i
+}
+
+/// Offset of the quote reopening the string literal closed at `close` after
+/// a concatenation (`' . $size . '`, `' + size + '`), or None if the string
+/// is not concatenated with another.
+fn concat_end(text: &str, close: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let is_operator = |c: u8| c == b'.' || c == b'+';
+    let rest = text[close + 1..].trim_start();
+    if !rest.bytes().next().is_some_and(is_operator) {
+        return None;
+    }
+    let mut i = bytes.len() - rest.len() + 1;
+    let (mut depth, mut after_operator) = (0i32, true);
+    while i < bytes.len() {
+        match bytes[i] {
+            c if c == bytes[close] && depth == 0 && after_operator => return Some(i),
+            b'"' | b'\'' => {
+                i = string_end(text, i);
+                after_operator = false;
+            }
+            b'(' | b'[' => depth += 1,
+            b')' | b']' => depth -= 1,
+            b';' if depth == 0 => return None,
+            c if c.is_ascii_whitespace() => {}
+            c => after_operator = depth == 0 && is_operator(c),
+        }
+        i += 1;
+    }
+    None
+}
 
-    let result = if let Some(m) = last_match {
-        let quote_char = before[m.end() - 1..m.end()].chars().next().unwrap();
-        let inside = &before[m.end()..];
-        !inside.contains(quote_char)
+/// Collects the class list text from `start` to `end`, less the words glued
+/// to an interpolation (or printing PHP block) `after` or `before` it
+/// (`btn-${size}`), which are only part of a class name.
+fn push_chunk(text: &str, start: usize, end: usize, after: bool, before: bool, out: &mut Found) {
+    let chunk = &text[start..end];
+    let lo = if after {
+        chunk.find(char::is_whitespace).unwrap_or(chunk.len())
+    } else {
+        0
+    };
+    let hi = if before {
+        chunk.rfind(char::is_whitespace).map_or(0, |i| i + 1)
     } else {
-        false
+        chunk.len()
     };
+    if lo < hi || (lo == hi && !after && !before) {
+        out.values.push(ClassValue { start: start + lo, end: start + hi, bare_key: None });
+    }
+}
 
-    eprintln!("[css-class-lsp] is_in_class_attribute={result} cursor={cursor}");
-    result
+/// True if `word` is a plain class name rather than part of a template expression.
+pub fn is_class_name(word: &str) -> bool {
+    NAME_RE.is_match(word)
 }
 
-/// Returns the partial class name being typed (from last whitespace/quote to cursor).
-pub fn get_word_prefix(text: &str, pos: Position) -> String {
+/// Finds every class name in the class lists of `text` (attribute values,
+/// helper arguments). Tokens that are not plain class names (template
+/// expressions and the like) are skipped.
+pub fn class_tokens(text: &str, settings: &Settings) -> Vec<ClassToken> {
+    let mut tokens = Vec::new();
+    for value in class_values(text, settings) {
+        let list = &text[value.start..value.end];
+        for word in WORD_RE.find_iter(list) {
+            if is_class_name(word.as_str()) {
+                tokens.push(ClassToken {
+                    name: word.as_str().to_string(),
+                    start: value.start + word.start(),
+                    end: value.start + word.end(),
+                    bare_key: value.bare_key,
+                });
+            }
+        }
+    }
+    tokens
+}
+
+/// Returns the partial class name being typed (from last whitespace/quote, or
+/// the start of the class list, to cursor).
+pub fn get_word_prefix(text: &str, pos: Position, settings: &Settings) -> String {
     let cursor = match position_to_offset(text, pos) {
         Some(o) => o,
         None => return String::new(),
     };
+    // `[class.is-open]` and `class:active` lists start mid-word
+    let list_start = class_values(text, settings)
+        .iter()
+        .filter(|v| v.start <= cursor && cursor <= v.end)
+        .map(|v| match v.bare_key {
+            // `{ btn-pr }` scans as `btn - pr`, but is one name being typed
+            Some(_) => {
+                let name_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '-');
+                text[..cursor].trim_end_matches(name_char).len()
+            }
+            None => v.start,
+        })
+        .max()
+        .unwrap_or(0);
 
-    let before = &text[..cursor];
+    let before = &text[list_start..cursor];
     let prefix_start = before
-        .rfind(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+        .rfind(|c: char| c.is_whitespace() || c == '"' || c == '\'' || c == '`')
         .map(|i| i + 1)
         .unwrap_or(0);
 
     before[prefix_start..].to_string()
 }
+
+/// Returns the final segment of the class being typed (after any `md:hover:`
+/// variant chain and `!` modifier) and the byte span a completion should
+/// replace. Colons inside `[...]` arbitrary values do not split segments.
+pub fn get_utility_prefix(
+    text: &str,
+    pos: Position,
+    settings: &Settings,
+) -> Option<(String, usize, usize)> {
+    let cursor = position_to_offset(text, pos)?;
+    let word = get_word_prefix(text, pos, settings);
+
+    let mut depth = 0usize;
+    let mut segment = 0;
+    for (i, c) in word.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => segment = i + 1,
+            _ => {}
+        }
+    }
+    if word[segment..].starts_with('!') {
+        segment += 1;
+    }
+
+    let prefix = &word[segment..];
+    let start = cursor - prefix.len();
+    // An editor may have auto-closed the bracket of an arbitrary value.
+    let end = if prefix.contains('[') && !prefix.contains(']') && text[cursor..].starts_with(']') {
+        cursor + 1
+    } else {
+        cursor
+    };
+    Some((prefix.to_string(), start, end))
+}
+
+/// The quote to wrap a class name in if the cursor is on an unquoted object
+/// key (`clsx({ act| })`), which only takes identifiers as written.
+pub fn bare_key_at(text: &str, pos: Position, settings: &Settings) -> Option<char> {
+    let cursor = position_to_offset(text, pos)?;
+    class_values(text, settings)
+        .into_iter()
+        .find(|v| v.start <= cursor && cursor <= v.end)?
+        .bare_key
+}
+
+/// `name` written as an object key: as is if it is an identifier, else
+/// quoted with `quote` (`active`, `"is-active"`).
+pub fn object_key(name: &str, quote: char) -> String {
+    let identifier = !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if identifier {
+        name.to_string()
+    } else {
+        format!("{quote}{name}{quote}")
+    }
+}
+
+/// Returns the whole class token under the cursor and its byte span, if the
+/// cursor is in a class list (unquoted object keys included).
+pub fn get_word_at(
+    text: &str,
+    pos: Position,
+    settings: &Settings,
+) -> Option<(String, usize, usize)> {
+    let cursor = position_to_offset(text, pos)?;
+    let value = class_values(text, settings)
+        .into_iter()
+        .find(|v| v.start <= cursor && cursor <= v.end)?;
+    let start = text[value.start..cursor]
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| c.is_whitespace())
+        .map(|(i, c)| value.start + i + c.len_utf8())
+        .unwrap_or(value.start);
+    let end = text[cursor..value.end]
+        .find(char::is_whitespace)
+        .map(|i| cursor + i)
+        .unwrap_or(value.end);
+    if start == end {
+        return None;
+    }
+    Some((text[start..end].to_string(), start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The class names `text` uses, in document order.
+    fn classes(text: &str) -> Vec<String> {
+        class_tokens(text, &Settings::default()).into_iter().map(|t| t.name).collect()
+    }
+
+    /// The prefix completion would replace at the `|` in `marked`, or None
+    /// outside a class list.
+    fn completion_at(marked: &str) -> Option<String> {
+        let cursor = marked.find('|').unwrap();
+        let text = marked.replace('|', "");
+        let pos = LineIndex::new(&text).position(cursor);
+        let settings = Settings::default();
+        if !is_in_class_context(&text, pos, &settings) {
+            return None;
+        }
+        get_utility_prefix(&text, pos, &settings).map(|(prefix, _, _)| prefix)
+    }
+
+    #[test]
+    fn class_names_start_with_a_letter_underscore_or_dash() {
+        for name in ["btn", "-mt-2", "_hidden", "col-md-6"] {
+            assert!(is_class_name(name), "{name}");
+        }
+        for name in ["", "1col", "--x", "w-1/2", "btn.lg"] {
+            assert!(!is_class_name(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn template_tags_are_not_class_lists() {
+        assert_eq!(classes(r#"<div class="btn {{ open ? 'x' : 'y' }}"></div>"#), ["btn"]);
+        assert_eq!(
+            classes(r#"<div class="{% if on %}active{% endif %}"></div>"#),
+            ["active"]
+        );
+    }
+
+    #[test]
+    fn utility_prefix_skips_variants_and_modifiers() {
+        assert_eq!(completion_at(r#"<a class="md:hover:fl|">"#).as_deref(), Some("fl"));
+        assert_eq!(completion_at(r#"<a class="lg:!p|">"#).as_deref(), Some("p"));
+        assert_eq!(completion_at(r#"<a class="btn ho|">"#).as_deref(), Some("ho"));
+        assert_eq!(
+            completion_at(r#"<a class="hover:bg-[url(a:b)|">"#).as_deref(),
+            Some("bg-[url(a:b)")
+        );
+        assert_eq!(completion_at(r#"<a href="fl|">"#), None);
+    }
+
+    #[test]
+    fn utility_prefix_covers_an_auto_closed_bracket() {
+        let text = r#"<a class="sm:bg-[]">"#;
+        let cursor = text.find(']').unwrap();
+        let pos = LineIndex::new(text).position(cursor);
+        let (prefix, start, end) = get_utility_prefix(text, pos, &Settings::default()).unwrap();
+        assert_eq!(prefix, "bg-[");
+        assert_eq!(&text[start..end], "bg-[]");
+    }
+
+    #[test]
+    fn clsx_strings_and_object_keys() {
+        assert_eq!(
+            classes(r#"clsx("btn", isActive && "active", { "btn-lg": large, disabled })"#),
+            ["btn", "active", "btn-lg", "disabled"]
+        );
+        assert_eq!(classes(r#"clsx({ active: variant === "primary" })"#), ["active"]);
+        assert_eq!(completion_at(r#"clsx("btn", { act| })"#).as_deref(), Some("act"));
+        assert_eq!(completion_at(r#"clsx({ act|: on })"#).as_deref(), Some("act"));
+        assert_eq!(completion_at(r#"clsx({ btn-pr| })"#).as_deref(), Some("btn-pr"));
+        assert_eq!(completion_at(r#"clsx({ active: o| })"#), None);
+    }
+
+    #[test]
+    fn cva_base_variants_and_compound_class() {
+        let text = r#"const button = cva("btn", {
+  variants: { size: { sm: "btn-sm", lg: ["btn-lg", "px-4"] } },
+  compoundVariants: [{ size: "lg", class: "uppercase" }],
+  defaultVariants: { size: "sm" },
+});"#;
+        assert_eq!(classes(text), ["btn", "btn-sm", "btn-lg", "px-4", "uppercase"]);
+        assert_eq!(completion_at(r#"cva("btn", { defaultVariants: { size: "s|" } })"#), None);
+    }
+
+    #[test]
+    fn object_keys_are_quoted_when_not_identifiers() {
+        assert_eq!(object_key("active", '"'), "active");
+        assert_eq!(object_key("is-active", '"'), r#""is-active""#);
+        assert_eq!(object_key("w-1/2", '\''), "'w-1/2'");
+    }
+
+    #[test]
+    fn jsx_template_literals_and_ternaries() {
+        assert_eq!(
+            classes(r#"<div className={active ? "on" : "off"} />"#),
+            ["on", "off"]
+        );
+        assert_eq!(
+            classes(r#"<div className={variant === "primary" ? "btn-primary" : ""} />"#),
+            ["btn-primary"]
+        );
+        assert_eq!(
+            classes(r#"<div className={`card ${open ? "card-open" : ""} btn-${size}`} />"#),
+            ["card", "card-open"]
+        );
+        assert_eq!(completion_at(r#"<div className={`card ${x} ca|`} />"#).as_deref(), Some("ca"));
+        assert_eq!(completion_at(r#"<div className={`card ${x|}`} />"#), None);
+    }
+
+    #[test]
+    fn vue_and_svelte_bindings() {
+        assert_eq!(
+            classes(r#"<div :class="['btn', { active: isActive, 'is-open': open }]"></div>"#),
+            ["btn", "active", "is-open"]
+        );
+        assert_eq!(classes(r#"<div v-bind:class="on ? 'on' : 'off'"></div>"#), ["on", "off"]);
+        assert_eq!(
+            classes(r#"<div class={open ? "open" : ""} class:active={on}></div>"#),
+            ["open", "active"]
+        );
+        assert_eq!(
+            classes(r#"<div class="card {big ? 'card-lg' : ''}"></div>"#),
+            ["card", "card-lg"]
+        );
+        assert_eq!(completion_at(r#"<div :class="{ act| }"></div>"#).as_deref(), Some("act"));
+        assert_eq!(completion_at(r#"<div class:act|={on}></div>"#).as_deref(), Some("act"));
+    }
+
+    #[test]
+    fn bare_keys_take_the_other_quote() {
+        let text = r#"<div :class="{ act }"></div>"#;
+        let pos = LineIndex::new(text).position(text.find("act").unwrap() + 3);
+        assert_eq!(bare_key_at(text, pos, &Settings::default()), Some('\''));
+    }
+
+    #[test]
+    fn angular_ng_class_and_class_bindings() {
+        assert_eq!(
+            classes(r#"<div [ngClass]="{'is-open': open, closed: !open}"></div>"#),
+            ["is-open", "closed"]
+        );
+        assert_eq!(classes(r#"<div ngClass="card card-lg"></div>"#), ["card", "card-lg"]);
+        assert_eq!(classes(r#"<div [class.is-open]="open"></div>"#), ["is-open"]);
+        assert_eq!(
+            classes("@Component({ template: `<p [ngClass]=\"['lead', 'm-0']\"></p>` })"),
+            ["lead", "m-0"]
+        );
+        assert_eq!(completion_at(r#"<div [class.is-|]="open"></div>"#).as_deref(), Some("is-"));
+        assert_eq!(completion_at(r#"<div [class.open]="o|"></div>"#), None);
+    }
+
+    #[test]
+    fn php_blocks_and_echoed_markup() {
+        assert_eq!(
+            classes(r#"<div class="btn <?= $active ? 'btn-primary' : 'btn-light' ?>"></div>"#),
+            ["btn", "btn-primary", "btn-light"]
+        );
+        assert_eq!(
+            classes(r#"<li class="<?php if ($open): ?>active<?php endif; ?>"></li>"#),
+            ["active"]
+        );
+        assert_eq!(
+            classes(r#"<?php echo '<div class="btn ' . $size . ' card">'; ?>"#),
+            ["btn", "card"]
+        );
+        assert_eq!(classes(r#"<?php echo "<a class=\"btn\">"; ?>"#), ["btn"]);
+        assert_eq!(
+            completion_at(r#"<div class="<?= $on ? 'ac|' : '' ?>"></div>"#).as_deref(),
+            Some("ac")
+        );
+        assert_eq!(completion_at(r#"<div class="<?= $a|ctive ?>"></div>"#), None);
+    }
+}
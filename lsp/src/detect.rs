@@ -0,0 +1,284 @@
+use regex::Regex;
+use std::path::Path;
+
+use crate::frameworks::BootstrapVersion;
+use crate::scanner;
+use crate::settings::Settings;
+
+/// How each bundled framework shows up in a project:
+/// (identifier, npm package, composer package, stylesheet / CDN name).
+static SIGNATURES: &[(&str, &str, &str, &str)] = &[
+    ("bootstrap",  "bootstrap",        "twbs/bootstrap",  "bootstrap"),
+    ("bulma",      "bulma",            "jgthms/bulma",    "bulma"),
+    ("foundation", "foundation-sites", "zurb/foundation", "foundation"),
+    ("uikit",      "uikit",            "uikit/uikit",     "uikit"),
+    ("pico",       "@picocss/pico",    "",                "pico"),
+    ("tachyons",   "tachyons",         "",                "tachyons"),
+    ("bootstrap-icons", "bootstrap-icons", "twbs/bootstrap-icons", "bootstrap-icons"),
+    ("font-awesome", "@fortawesome/fontawesome-free", "fortawesome/font-awesome", "font-awesome"),
+    ("font-awesome", "font-awesome",   "",                "font-awesome"),
+];
+
+const MANIFESTS: &[&str] = &["package.json", "composer.json"];
+const LOCKFILES: &[&str] = &["package-lock.json", "yarn.lock", "pnpm-lock.yaml", "composer.lock"];
+
+/// True if a change to `path` can change which frameworks are detected.
+pub fn is_project_file(path: &Path, settings: &Settings) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| {
+            MANIFESTS.contains(&n)
+                || LOCKFILES.contains(&n)
+                || stylesheet_framework(n, settings).is_some()
+        })
+}
+
+/// Identifiers of the bundled frameworks a workspace root uses, judged from
+/// its manifests, lockfiles, vendored stylesheets and the `<link>` hrefs of
+/// its templates.
+pub fn frameworks(root: &Path, links: &[String], settings: &Settings) -> Vec<String> {
+    let package_json = read_json(&root.join("package.json"));
+    let composer_json = read_json(&root.join("composer.json"));
+    let lockfiles: String = LOCKFILES
+        .iter()
+        .filter_map(|f| std::fs::read_to_string(root.join(f)).ok())
+        .collect();
+    let vendored: Vec<&str> = scanner::find_css_files(root, settings)
+        .iter()
+        .filter_map(|p| p.file_name().and_then(|n| n.to_str()))
+        .filter_map(|n| stylesheet_framework(n, settings))
+        .collect();
+
+    let mut found = Vec::new();
+    for &(id, npm, composer, name) in SIGNATURES {
+        let declared = package_json.as_ref().is_some_and(|m| {
+            depends_on(m, &["dependencies", "devDependencies", "peerDependencies"], npm)
+        }) || (!composer.is_empty()
+            && composer_json
+                .as_ref()
+                .is_some_and(|m| depends_on(m, &["require", "require-dev"], composer)));
+        let locked = lockfile_mentions(&lockfiles, npm)
+            || (!composer.is_empty() && lockfiles.contains(&format!("\"name\": \"{composer}\"")));
+        let linked = links
+            .iter()
+            .any(|href| link_mentions(href, name) || link_mentions(href, npm));
+
+        let used = declared || locked || linked || vendored.contains(&id);
+        if used && !found.iter().any(|f| f == id) {
+            eprintln!(
+                "[css-class-lsp] detected {id} (declared={declared} locked={locked} linked={linked})"
+            );
+            found.push(id.to_string());
+        }
+    }
+    found
+}
+
+/// Works out which Bootstrap major version a workspace uses: the installed
+/// package first, then the version ranges in `package.json` and
+/// `composer.json`, then the banner of a vendored `bootstrap*.css`, then a
+/// versioned CDN `<link>`.
+pub fn bootstrap_version(
+    root: &Path,
+    links: &[String],
+    settings: &Settings,
+) -> Option<BootstrapVersion> {
+    for installed in ["node_modules/bootstrap/package.json", "vendor/twbs/bootstrap/package.json"] {
+        if let Some(manifest) = read_json(&root.join(installed)) {
+            if let Some(version) = manifest.get("version").and_then(|v| v.as_str()) {
+                return parse_major(version);
+            }
+        }
+    }
+
+    let declared = [
+        ("package.json", &["dependencies", "devDependencies"][..], "bootstrap"),
+        ("composer.json", &["require", "require-dev"][..], "twbs/bootstrap"),
+    ];
+    for (file, sections, package) in declared {
+        let Some(manifest) = read_json(&root.join(file)) else {
+            continue;
+        };
+        for section in sections {
+            if let Some(range) = manifest
+                .get(section)
+                .and_then(|deps| deps.get(package))
+                .and_then(|v| v.as_str())
+            {
+                return parse_major(range);
+            }
+        }
+    }
+
+    let banner_re = Regex::new(r"Bootstrap\s+v(\d+)").unwrap();
+    for path in scanner::find_css_files(root, settings) {
+        let is_bootstrap = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("bootstrap"));
+        if !is_bootstrap {
+            continue;
+        }
+        let Ok(css) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Some(c) = banner_re.captures(&css) {
+            return c[1].parse().ok().and_then(BootstrapVersion::from_major);
+        }
+    }
+
+    // e.g. .../npm/bootstrap@4.6.2/dist/... or .../bootstrap/3.4.1/css/...
+    let cdn_re = Regex::new(r"\bbootstrap[@/](\d+)\.").unwrap();
+    links
+        .iter()
+        .find_map(|href| cdn_re.captures(href))
+        .and_then(|c| c[1].parse().ok())
+        .and_then(BootstrapVersion::from_major)
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let text = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+fn depends_on(manifest: &serde_json::Value, sections: &[&str], package: &str) -> bool {
+    sections
+        .iter()
+        .any(|section| manifest.get(section).and_then(|deps| deps.get(package)).is_some())
+}
+
+/// True if an npm, yarn or pnpm lockfile lists `package`. The name must be
+/// followed by a version or path separator, so `bootstrap` does not match
+/// `bootstrap-icons`.
+fn lockfile_mentions(lockfiles: &str, package: &str) -> bool {
+    let re = Regex::new(&format!(
+        r#"(?m)(?:node_modules/|^"?|^\s+/?){}[@:"/]"#,
+        regex::escape(package)
+    ))
+    .unwrap();
+    re.is_match(lockfiles)
+}
+
+/// True if a `<link>` href loads the framework's stylesheet, e.g.
+/// `.../bootstrap@5.3.3/dist/css/bootstrap.min.css` for `bootstrap` but not
+/// `.../bootstrap-icons.min.css`.
+fn link_mentions(href: &str, name: &str) -> bool {
+    let re = Regex::new(&format!(
+        r"(?:^|[^a-zA-Z0-9_-]){}(?:[@./]|$)",
+        regex::escape(name)
+    ))
+    .unwrap();
+    re.is_match(href)
+}
+
+/// The framework a vendored stylesheet belongs to, from its file name
+/// (`bootstrap.min.css`, `bulma.css`), if any.
+fn stylesheet_framework(file_name: &str, settings: &Settings) -> Option<&'static str> {
+    SIGNATURES.iter().map(|&(id, .., name)| (id, name)).find_map(|(id, name)| {
+        let rest = file_name.strip_prefix(name)?;
+        (rest.starts_with('.') && settings.is_css_path(Path::new(file_name))).then_some(id)
+    })
+}
+
+/// The major version of a version or range such as `^4.6.2`, `~3.4` or `v5.3.0`.
+fn parse_major(version: &str) -> Option<BootstrapVersion> {
+    let digits: String = version
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok().and_then(BootstrapVersion::from_major)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// A fresh directory under the system temp dir holding `files`.
+    fn project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = format!("css-class-lsp-{name}-{}", std::process::id());
+        let root = std::env::temp_dir().join(dir);
+        let _ = std::fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        }
+        std::fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn major_versions_from_ranges() {
+        assert_eq!(parse_major("^4.6.2"), Some(BootstrapVersion::V4));
+        assert_eq!(parse_major("~3.4"), Some(BootstrapVersion::V3));
+        assert_eq!(parse_major("v5.3.0"), Some(BootstrapVersion::V5));
+        assert_eq!(parse_major(">=5 <6"), Some(BootstrapVersion::V5));
+        assert_eq!(parse_major("2.3.2"), None);
+        assert_eq!(parse_major("latest"), None);
+    }
+
+    #[test]
+    fn bootstrap_version_sources_in_order() {
+        let settings = Settings::default();
+        let installed = project(
+            "installed",
+            &[
+                ("package.json", r#"{ "dependencies": { "bootstrap": "^5.3.0" } }"#),
+                ("node_modules/bootstrap/package.json", r#"{ "version": "4.6.2" }"#),
+            ],
+        );
+        assert_eq!(bootstrap_version(&installed, &[], &settings), Some(BootstrapVersion::V4));
+
+        let composer = project(
+            "composer",
+            &[("composer.json", r#"{ "require": { "twbs/bootstrap": "3.4.*" } }"#)],
+        );
+        assert_eq!(bootstrap_version(&composer, &[], &settings), Some(BootstrapVersion::V3));
+
+        let css = "/*!\n * Bootstrap  v4.6.2 (https://getbootstrap.com/)\n */";
+        let banner = project("banner", &[("css/bootstrap.min.css", css)]);
+        assert_eq!(bootstrap_version(&banner, &[], &settings), Some(BootstrapVersion::V4));
+
+        let empty = project("cdn", &[]);
+        let links = ["https://cdn.example.com/bootstrap/3.4.1/css/bootstrap.min.css".into()];
+        assert_eq!(bootstrap_version(&empty, &links, &settings), Some(BootstrapVersion::V3));
+        assert_eq!(bootstrap_version(&empty, &[], &settings), None);
+
+        for root in [installed, composer, banner, empty] {
+            let _ = std::fs::remove_dir_all(root);
+        }
+    }
+
+    #[test]
+    fn frameworks_from_manifests_lockfiles_stylesheets_and_links() {
+        let settings = Settings::default();
+        let root = project(
+            "frameworks",
+            &[
+                ("package.json", r#"{ "devDependencies": { "bulma": "^1.0.0" } }"#),
+                ("package-lock.json", r#"{ "packages": { "node_modules/uikit": {} } }"#),
+                ("composer.json", r#"{ "require": { "zurb/foundation": "^6.8" } }"#),
+                ("assets/tachyons.min.css", ".pa3 { }"),
+                ("assets/bootstrap-theme.css", ".x { }"),
+            ],
+        );
+        let links = ["https://cdn.example.com/bootstrap-icons@1.11.3/font/bootstrap-icons.min.css"
+            .to_string()];
+        assert_eq!(
+            frameworks(&root, &links, &settings),
+            ["bulma", "foundation", "uikit", "tachyons", "bootstrap-icons"]
+        );
+        assert!(frameworks(&project("none", &[]), &[], &settings).is_empty());
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn package_names_match_whole() {
+        assert!(lockfile_mentions("  /bootstrap@5.3.3:\n", "bootstrap"));
+        assert!(!lockfile_mentions("\"node_modules/bootstrap-icons\": {}", "bootstrap"));
+        assert!(link_mentions("/npm/bootstrap@5.3.3/dist/css/bootstrap.min.css", "bootstrap"));
+        assert!(!link_mentions("/font/bootstrap-icons.min.css", "bootstrap"));
+    }
+}
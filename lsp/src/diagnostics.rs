@@ -0,0 +1,189 @@
+use std::collections::{BTreeSet, HashSet};
+
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, Range};
+
+use crate::context::{self, LineIndex};
+use crate::scanner::{self, Stylesheet};
+use crate::settings::Settings;
+
+pub const SOURCE: &str = "css-class-lsp";
+pub const UNDEFINED_CLASS: &str = "undefined-class";
+pub const UNUSED_CLASS: &str = "unused-class";
+
+/// How many replacements a quick fix offers for one misspelled class.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Warns about every class attribute token that no stylesheet, framework or
+/// `<style>` block of the document itself defines. Classes starting with one
+/// of `partial` (prefixes whose classes are only partly known) are skipped.
+/// Replacements are looked up by the code action, only for the warning under
+/// the cursor.
+pub fn undefined_classes(
+    text: &str,
+    settings: &Settings,
+    known: &BTreeSet<String>,
+    partial: &[&str],
+) -> Vec<Diagnostic> {
+    let lines = LineIndex::new(text);
+    let local = scanner::style_block_classes(text);
+    context::class_tokens(text, settings)
+        .into_iter()
+        .filter(|t| !known.contains(&t.name) && !local.contains(&t.name))
+        .filter(|t| !partial.iter().any(|p| t.name.starts_with(p)))
+        .map(|t| Diagnostic {
+            range: Range::new(lines.position(t.start), lines.position(t.end)),
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String(UNDEFINED_CLASS.to_string())),
+            source: Some(SOURCE.to_string()),
+            message: format!("Unknown CSS class `{}`", t.name),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Hints (rendered faded) on stylesheet selectors whose class no template references.
+pub fn unused_classes(sheet: &Stylesheet, referenced: &HashSet<String>) -> Vec<Diagnostic> {
+    sheet
+        .selectors
+        .iter()
+        .filter(|s| !referenced.contains(&s.name))
+        .map(|s| Diagnostic {
+            range: s.range,
+            severity: Some(DiagnosticSeverity::HINT),
+            code: Some(NumberOrString::String(UNUSED_CLASS.to_string())),
+            source: Some(SOURCE.to_string()),
+            message: format!("CSS class `.{}` is not used in any template", s.name),
+            tags: Some(vec![DiagnosticTag::UNNECESSARY]),
+            data: Some(serde_json::Value::String(s.name.clone())),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// The class an unused-class diagnostic is about.
+pub fn unused_class_name(diagnostic: &Diagnostic) -> Option<&str> {
+    diagnostic.data.as_ref()?.as_str()
+}
+
+/// True if `diagnostic` is one of our undefined-class warnings.
+pub fn is_undefined_class(diagnostic: &Diagnostic) -> bool {
+    diagnostic.source.as_deref() == Some(SOURCE)
+        && diagnostic.code == Some(NumberOrString::String(UNDEFINED_CLASS.to_string()))
+}
+
+/// Known classes closest to `name` by edit distance, best first. Only names
+/// within roughly a third of `name`'s length are considered close.
+pub fn closest_matches(name: &str, known: &BTreeSet<String>) -> Vec<String> {
+    let limit = (name.chars().count() / 3).max(1);
+    let mut scored: Vec<(usize, &String)> = known
+        .iter()
+        .filter(|k| k.len().abs_diff(name.len()) <= limit)
+        .map(|k| (edit_distance(name, k), k))
+        .filter(|&(d, _)| d <= limit)
+        .collect();
+    scored.sort();
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, k)| k.clone())
+        .collect()
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    /// Each warning as `line:start-end name`.
+    fn warnings(text: &str, names: &[&str]) -> Vec<String> {
+        undefined_classes(text, &Settings::default(), &known(names), &[])
+            .iter()
+            .map(|d| {
+                let (start, end) = (d.range.start, d.range.end);
+                let name = d.message.split('`').nth(1).unwrap_or_default();
+                format!("{}:{}-{} {name}", start.line, start.character, end.character)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn unknown_tokens_are_flagged_with_their_range() {
+        let html = "<div class=\"btn d-felx\">\n  <p class=\"lead\"></p>\n</div>";
+        assert_eq!(warnings(html, &["btn", "lead"]), ["0:16-22 d-felx"]);
+        let warning = &undefined_classes(html, &Settings::default(), &known(&["btn"]), &[])[0];
+        assert!(is_undefined_class(warning));
+        assert_eq!(warning.severity, Some(DiagnosticSeverity::WARNING));
+    }
+
+    #[test]
+    fn local_style_blocks_and_dynamic_values_are_not_flagged() {
+        let html = "<style>.hero { }</style>\n<div class=\"hero {{ size }} <?= $extra ?>\"></div>";
+        assert!(warnings(html, &[]).is_empty());
+        let jsx = r#"<div className={`card ${open ? "card-open" : ""} card-${size}`} />"#;
+        assert_eq!(warnings(jsx, &["card"]), ["0:32-41 card-open"]);
+    }
+
+    #[test]
+    fn closest_matches_rank_by_edit_distance() {
+        let classes = known(&["d-flex", "d-grid", "d-none", "flex", "btn", "btn-lg"]);
+        assert_eq!(closest_matches("d-felx", &classes), ["d-flex"]);
+        assert_eq!(closest_matches("d-nome", &classes), ["d-none"]);
+        let spacing = known(&["p-1", "p-2", "p-20", "p-3", "m-2"]);
+        assert_eq!(closest_matches("p-2x", &spacing), ["p-2", "p-20"]);
+        assert_eq!(closest_matches("p-4", &spacing), ["p-1", "p-2", "p-3"]);
+        assert_eq!(closest_matches("btm", &classes), ["btn"]);
+        assert!(closest_matches("card", &classes).is_empty());
+    }
+
+    #[test]
+    fn edit_distance_counts_chars() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("é-x", "e-x"), 1);
+    }
+
+    #[test]
+    fn unused_hints_name_the_unreferenced_selectors() {
+        let sheet = scanner::parse_stylesheet(
+            std::path::Path::new("app.css"),
+            ".used { }\n.stale, .used:hover { }",
+        );
+        let referenced: HashSet<String> = ["used".to_string()].into();
+        let hints = unused_classes(&sheet, &referenced);
+        assert_eq!(hints.len(), 1);
+        assert_eq!(unused_class_name(&hints[0]), Some("stale"));
+        assert_eq!(hints[0].range.start, tower_lsp::lsp_types::Position::new(1, 0));
+        assert_eq!(hints[0].tags, Some(vec![DiagnosticTag::UNNECESSARY]));
+    }
+
+    #[test]
+    fn partially_known_prefixes_are_not_flagged() {
+        let html = r#"<i class="bi bi-arrow-up-right fa-user-plus"></i>"#;
+        let warnings = undefined_classes(html, &Settings::default(), &known(&["bi"]), &["bi-"]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "Unknown CSS class `fa-user-plus`");
+    }
+}
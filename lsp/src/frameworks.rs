@@ -1,13 +1,180 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-/// Returns all Bootstrap 5.3 class names (components + generated utilities).
-pub fn bootstrap5_classes() -> HashSet<String> {
+/// Display name for classes that come from the bundled Bootstrap 5 list.
+pub const BOOTSTRAP5_NAME: &str = "Bootstrap 5.3";
+
+/// A class vocabulary offered alongside the workspace's own stylesheets.
+#[derive(Default)]
+pub struct Framework {
+    /// Display name, shown as the completion detail.
+    pub name: String,
+    pub classes: HashSet<String>,
+    /// Class → CSS color it applies, for swatches.
+    pub colors: HashMap<String, String>,
+    /// Variant prefixes (`md`, `hover`) that can precede a class before a `:`.
+    pub variants: Vec<String>,
+    /// Utility prefixes that take an arbitrary `[value]`, with an example value.
+    pub arbitrary: Vec<(String, String)>,
+    /// Class → Markdown description, shown on hover and in completion docs.
+    pub descriptions: HashMap<String, String>,
+    /// Class → category, shown next to the framework name in completions.
+    pub categories: HashMap<String, String>,
+    /// Set when `classes` lists only some of the classes with this prefix
+    /// (icon names bundled without the icon font's stylesheet), so unknown
+    /// ones are not reported.
+    pub partial_prefix: Option<String>,
+}
+
+/// Builds the bundled framework with the given identifier (`bulma`,
+/// `foundation`, `uikit`, `pico` or `tachyons`). Bootstrap depends on the
+/// workspace's version and Sass theme, so the caller builds it with
+/// [`bootstrap`].
+pub fn by_id(id: &str) -> Option<Framework> {
+    match id {
+        "bulma" => Some(bulma()),
+        "foundation" => Some(foundation()),
+        "uikit" => Some(uikit()),
+        "pico" => Some(pico()),
+        "tachyons" => Some(tachyons()),
+        _ => None,
+    }
+}
+
+fn bundled(name: &str, classes: HashSet<String>, colors: HashMap<String, String>) -> Framework {
+    Framework {
+        name: name.to_string(),
+        classes,
+        colors,
+        ..Default::default()
+    }
+}
+
+/// A bundled Bootstrap major version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BootstrapVersion {
+    V3,
+    V4,
+    V5,
+}
+
+impl BootstrapVersion {
+    pub fn from_major(major: u32) -> Option<Self> {
+        match major {
+            3 => Some(BootstrapVersion::V3),
+            4 => Some(BootstrapVersion::V4),
+            5 => Some(BootstrapVersion::V5),
+            _ => None,
+        }
+    }
+
+    /// The version pinned by a `bootstrap3`, `bootstrap4` or `bootstrap5`
+    /// identifier. None for plain `bootstrap`.
+    pub fn from_id(id: &str) -> Option<Self> {
+        id.strip_prefix("bootstrap")?.parse().ok().and_then(Self::from_major)
+    }
+}
+
+/// The Sass maps Bootstrap 5 generates its responsive and color utilities
+/// from: the stock values, or a project's overrides.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BootstrapTheme {
+    /// `$grid-breakpoints` names, smallest first (`xs`, `sm`, …).
+    pub breakpoints: Vec<String>,
+    /// `$spacers` keys (`0` … `5`).
+    pub spacers: Vec<String>,
+    /// `$theme-colors` names and their CSS values.
+    pub colors: Vec<(String, String)>,
+}
+
+impl Default for BootstrapTheme {
+    fn default() -> Self {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        BootstrapTheme {
+            breakpoints: strings(&["xs", "sm", "md", "lg", "xl", "xxl"]),
+            spacers: strings(&["0", "1", "2", "3", "4", "5"]),
+            colors: THEME_COLORS
+                .iter()
+                .map(|&(name, base, ..)| (name.to_string(), base.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl BootstrapTheme {
+    /// Class-name infix per breakpoint: none for the smallest, then `sm-`,
+    /// `md-`, …
+    fn infixes(&self) -> Vec<String> {
+        self.breakpoints
+            .iter()
+            .enumerate()
+            .map(|(i, name)| if i == 0 { String::new() } else { format!("{name}-") })
+            .collect()
+    }
+}
+
+/// The bundled Bootstrap vocabulary for `version`. `theme` only applies to
+/// Bootstrap 5, whose utilities are generated from it.
+pub fn bootstrap(version: BootstrapVersion, theme: &BootstrapTheme) -> Framework {
+    match version {
+        BootstrapVersion::V3 => bundled(BOOTSTRAP3_NAME, bootstrap3_classes(), bootstrap3_colors()),
+        BootstrapVersion::V4 => bundled(BOOTSTRAP4_NAME, bootstrap4_classes(), bootstrap4_colors()),
+        BootstrapVersion::V5 => {
+            bundled(BOOTSTRAP5_NAME, bootstrap5_classes(theme), bootstrap5_colors(theme))
+        }
+    }
+}
+
+/// Returns all Bootstrap 5.3 class names (components + utilities generated
+/// from `theme`).
+pub fn bootstrap5_classes(theme: &BootstrapTheme) -> HashSet<String> {
     let mut s: HashSet<String> = STATIC.iter().map(|c| c.to_string()).collect();
 
-    let bp = ["", "sm-", "md-", "lg-", "xl-", "xxl-"];
+    let bp = theme.infixes();
+
+    // ── Grid & responsive components ─────────────────────────────────────────
+    for (i, name) in theme.breakpoints.iter().enumerate() {
+        let b = &bp[i];
+        // `col-md`, `navbar-expand-md`: the smallest breakpoint has no infix.
+        let bare = |base: &str| if i == 0 { base.to_string() } else { format!("{base}-{name}") };
+        s.insert(bare("col"));
+        s.insert(bare("navbar-expand"));
+        s.insert(bare("table-responsive"));
+        s.insert(format!("col-{b}auto"));
+        for n in 1..=12 {
+            s.insert(format!("col-{b}{n}"));
+        }
+        for n in 0..12 {
+            if i > 0 || n > 0 {
+                s.insert(format!("offset-{b}{n}"));
+            }
+        }
+        for v in ["1","2","3","4","5","6","auto"] {
+            s.insert(format!("row-cols-{b}{v}"));
+        }
+        for v in ["start","end"] {
+            s.insert(format!("dropdown-menu-{b}{v}"));
+        }
+        if i > 0 {
+            s.insert(format!("container-{name}"));
+            s.insert(format!("offcanvas-{name}"));
+            s.insert(format!("modal-fullscreen-{name}-down"));
+        }
+    }
+
+    // ── Theme colors ─────────────────────────────────────────────────────────
+    for (c, _) in &theme.colors {
+        for prefix in ["btn", "btn-outline", "alert", "table", "text", "bg", "border",
+                       "link", "text-bg", "link-underline"] {
+            s.insert(format!("{prefix}-{c}"));
+        }
+        s.insert(format!("text-{c}-emphasis"));
+        s.insert(format!("link-{c}-emphasis"));
+        s.insert(format!("bg-{c}-subtle"));
+        s.insert(format!("border-{c}-subtle"));
+    }
 
     // ── Display ──────────────────────────────────────────────────────────────
-    for b in bp {
+    for b in &bp {
         for v in ["none","inline","inline-block","block","grid","inline-grid",
                   "table","table-row","table-cell","flex","inline-flex"] {
             s.insert(format!("d-{b}{v}"));
@@ -21,8 +188,8 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     // ── Spacing m / p ────────────────────────────────────────────────────────
     for prop in ["m", "p"] {
         for dir in ["", "t", "b", "s", "e", "x", "y"] {
-            for b in bp {
-                for v in ["0","1","2","3","4","5","auto"] {
+            for b in &bp {
+                for v in theme.spacers.iter().map(String::as_str).chain(["auto"]) {
                     s.insert(format!("{prop}{dir}-{b}{v}"));
                 }
             }
@@ -30,16 +197,16 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     }
     // Negative margins
     for dir in ["", "t", "b", "s", "e", "x", "y"] {
-        for b in bp {
-            for v in ["1","2","3","4","5"] {
+        for b in &bp {
+            for v in theme.spacers.iter().filter(|v| *v != "0") {
                 s.insert(format!("m{dir}-{b}n{v}"));
             }
         }
     }
 
     // ── Gap ──────────────────────────────────────────────────────────────────
-    for b in bp {
-        for v in ["0","1","2","3","4","5"] {
+    for b in &bp {
+        for v in &theme.spacers {
             s.insert(format!("gap-{b}{v}"));
             s.insert(format!("row-gap-{b}{v}"));
             s.insert(format!("column-gap-{b}{v}"));
@@ -50,7 +217,7 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     }
 
     // ── Flex ─────────────────────────────────────────────────────────────────
-    for b in bp {
+    for b in &bp {
         for v in ["row","row-reverse","column","column-reverse"] {
             s.insert(format!("flex-{b}{v}"));
         }
@@ -77,14 +244,14 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     }
 
     // ── Float ────────────────────────────────────────────────────────────────
-    for b in bp {
+    for b in &bp {
         for v in ["start","end","none"] {
             s.insert(format!("float-{b}{v}"));
         }
     }
 
     // ── Object fit ───────────────────────────────────────────────────────────
-    for b in bp {
+    for b in &bp {
         for v in ["contain","cover","fill","scale","none"] {
             s.insert(format!("object-fit-{b}{v}"));
         }
@@ -92,19 +259,14 @@ pub fn bootstrap5_classes() -> HashSet<String> {
 
     // ── Text ─────────────────────────────────────────────────────────────────
     // Alignment (responsive)
-    for b in bp {
+    for b in &bp {
         for v in ["start","center","end"] {
             s.insert(format!("text-{b}{v}"));
         }
     }
     // Other text utilities
     for v in ["wrap","nowrap","break","truncate","lowercase","uppercase","capitalize",
-              "muted","white","black","reset",
-              "primary","secondary","success","danger","warning","info","light","dark",
-              "primary-emphasis","secondary-emphasis","success-emphasis",
-              "danger-emphasis","warning-emphasis","info-emphasis",
-              "light-emphasis","dark-emphasis",
-              "body","body-secondary","body-tertiary"] {
+              "muted","white","black","reset","body","body-secondary","body-tertiary"] {
         s.insert(format!("text-{v}"));
     }
     for v in ["none","underline","line-through"] {
@@ -129,10 +291,7 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     }
 
     // ── Background ───────────────────────────────────────────────────────────
-    for v in ["primary","secondary","success","danger","warning","info","light","dark",
-              "white","black","transparent","body","body-secondary","body-tertiary",
-              "primary-subtle","secondary-subtle","success-subtle","danger-subtle",
-              "warning-subtle","info-subtle","light-subtle","dark-subtle","gradient"] {
+    for v in ["white","black","transparent","body","body-secondary","body-tertiary","gradient"] {
         s.insert(format!("bg-{v}"));
     }
     for v in ["10","25","50","75","100"] {
@@ -144,9 +303,7 @@ pub fn bootstrap5_classes() -> HashSet<String> {
         s.insert(format!("border{dir}"));
         s.insert(format!("border{dir}-0"));
     }
-    for v in ["primary","secondary","success","danger","warning","info","light","dark",
-              "white","black","primary-subtle","secondary-subtle","success-subtle",
-              "danger-subtle","warning-subtle","info-subtle","light-subtle","dark-subtle"] {
+    for v in ["white","black"] {
         s.insert(format!("border-{v}"));
     }
     for v in ["1","2","3","4","5"] {
@@ -232,22 +389,12 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     }
 
     // ── Link utilities ───────────────────────────────────────────────────────
-    for c in ["primary","secondary","success","danger","warning","info","light","dark"] {
-        s.insert(format!("link-{c}"));
-        s.insert(format!("link-{c}-emphasis"));
-        s.insert(format!("link-underline-{c}"));
-    }
     s.insert("stretched-link".into());
     s.insert("link-underline".into());
     for v in ["10","25","50","75","100"] { s.insert(format!("link-opacity-{v}")); }
     for v in ["1","2","3"] { s.insert(format!("link-offset-{v}")); }
     s.insert("icon-link".into());
 
-    // ── Color helpers (text-bg-*) ─────────────────────────────────────────────
-    for c in ["primary","secondary","success","danger","warning","info","light","dark"] {
-        s.insert(format!("text-bg-{c}"));
-    }
-
     // ── Ratio ────────────────────────────────────────────────────────────────
     s.insert("ratio".into());
     for v in ["1x1","4x3","16x9","21x9"] { s.insert(format!("ratio-{v}")); }
@@ -255,42 +402,63 @@ pub fn bootstrap5_classes() -> HashSet<String> {
     s
 }
 
+/// Bootstrap 5.3 default `$theme-colors`, with the `-emphasis` text,
+/// `-subtle` background and `-subtle` border variants of each.
+static THEME_COLORS: &[(&str, &str, &str, &str, &str)] = &[
+    // name         base       emphasis   bg-subtle  border-subtle
+    ("primary",   "#0d6efd", "#052c65", "#cfe2ff", "#9ec5fe"),
+    ("secondary", "#6c757d", "#2b2f32", "#e2e3e5", "#c4c8cb"),
+    ("success",   "#198754", "#0a3622", "#d1e7dd", "#a3cfbb"),
+    ("info",      "#0dcaf0", "#055160", "#cff4fc", "#9eeaf9"),
+    ("warning",   "#ffc107", "#664d03", "#fff3cd", "#ffe69c"),
+    ("danger",    "#dc3545", "#58151c", "#f8d7da", "#f1aeb5"),
+    ("light",     "#f8f9fa", "#495057", "#fcfcfd", "#e9ecef"),
+    ("dark",      "#212529", "#495057", "#ced4da", "#adb5bd"),
+];
+
+/// Maps Bootstrap 5.3 color utilities and colored components to the CSS
+/// color they render with (light color mode). The `-emphasis` and `-subtle`
+/// variants are only known for theme colors left at their stock value.
+pub fn bootstrap5_colors(theme: &BootstrapTheme) -> HashMap<String, String> {
+    let mut m: HashMap<String, String> = HashMap::new();
+
+    for (c, base) in &theme.colors {
+        for prefix in ["text", "bg", "border", "text-bg", "link", "btn", "btn-outline",
+                       "alert", "table", "link-underline"] {
+            m.insert(format!("{prefix}-{c}"), base.clone());
+        }
+        let stock = THEME_COLORS
+            .iter()
+            .find(|row| row.0 == c && row.1.eq_ignore_ascii_case(base));
+        if let Some(&(_, _, emphasis, bg_subtle, border_subtle)) = stock {
+            m.insert(format!("text-{c}-emphasis"), emphasis.into());
+            m.insert(format!("link-{c}-emphasis"), emphasis.into());
+            m.insert(format!("bg-{c}-subtle"), bg_subtle.into());
+            m.insert(format!("border-{c}-subtle"), border_subtle.into());
+        }
+    }
+
+    for prefix in ["text", "bg", "border"] {
+        m.insert(format!("{prefix}-white"), "#ffffff".into());
+        m.insert(format!("{prefix}-black"), "#000000".into());
+    }
+    m.insert("text-body".into(), "#212529".into());
+    m.insert("text-body-secondary".into(), "rgba(33, 37, 41, 0.75)".into());
+    m.insert("text-body-tertiary".into(), "rgba(33, 37, 41, 0.5)".into());
+    m.insert("text-muted".into(), "rgba(33, 37, 41, 0.75)".into());
+    m.insert("bg-body".into(), "#ffffff".into());
+    m.insert("bg-body-secondary".into(), "#e9ecef".into());
+    m.insert("bg-body-tertiary".into(), "#f8f9fa".into());
+    m.insert("bg-transparent".into(), "transparent".into());
+
+    m
+}
+
 // ── Static component & layout classes ────────────────────────────────────────
 static STATIC: &[&str] = &[
     // Grid
-    "col","col-auto","col-1","col-2","col-3","col-4","col-5","col-6",
-    "col-7","col-8","col-9","col-10","col-11","col-12",
-    "col-sm","col-sm-auto","col-sm-1","col-sm-2","col-sm-3","col-sm-4","col-sm-5","col-sm-6",
-    "col-sm-7","col-sm-8","col-sm-9","col-sm-10","col-sm-11","col-sm-12",
-    "col-md","col-md-auto","col-md-1","col-md-2","col-md-3","col-md-4","col-md-5","col-md-6",
-    "col-md-7","col-md-8","col-md-9","col-md-10","col-md-11","col-md-12",
-    "col-lg","col-lg-auto","col-lg-1","col-lg-2","col-lg-3","col-lg-4","col-lg-5","col-lg-6",
-    "col-lg-7","col-lg-8","col-lg-9","col-lg-10","col-lg-11","col-lg-12",
-    "col-xl","col-xl-auto","col-xl-1","col-xl-2","col-xl-3","col-xl-4","col-xl-5","col-xl-6",
-    "col-xl-7","col-xl-8","col-xl-9","col-xl-10","col-xl-11","col-xl-12",
-    "col-xxl","col-xxl-auto","col-xxl-1","col-xxl-2","col-xxl-3","col-xxl-4","col-xxl-5","col-xxl-6",
-    "col-xxl-7","col-xxl-8","col-xxl-9","col-xxl-10","col-xxl-11","col-xxl-12",
     "col-form-label","col-form-label-lg","col-form-label-sm",
-    "row","row-cols-1","row-cols-2","row-cols-3","row-cols-4","row-cols-5","row-cols-6","row-cols-auto",
-    "row-cols-sm-1","row-cols-sm-2","row-cols-sm-3","row-cols-sm-4","row-cols-sm-5","row-cols-sm-6","row-cols-sm-auto",
-    "row-cols-md-1","row-cols-md-2","row-cols-md-3","row-cols-md-4","row-cols-md-5","row-cols-md-6","row-cols-md-auto",
-    "row-cols-lg-1","row-cols-lg-2","row-cols-lg-3","row-cols-lg-4","row-cols-lg-5","row-cols-lg-6","row-cols-lg-auto",
-    "row-cols-xl-1","row-cols-xl-2","row-cols-xl-3","row-cols-xl-4","row-cols-xl-5","row-cols-xl-6","row-cols-xl-auto",
-    "row-cols-xxl-1","row-cols-xxl-2","row-cols-xxl-3","row-cols-xxl-4","row-cols-xxl-5","row-cols-xxl-6","row-cols-xxl-auto",
-    "container","container-fluid","container-sm","container-md","container-lg","container-xl","container-xxl",
-    // Offsets
-    "offset-1","offset-2","offset-3","offset-4","offset-5","offset-6",
-    "offset-7","offset-8","offset-9","offset-10","offset-11",
-    "offset-sm-0","offset-sm-1","offset-sm-2","offset-sm-3","offset-sm-4","offset-sm-5",
-    "offset-sm-6","offset-sm-7","offset-sm-8","offset-sm-9","offset-sm-10","offset-sm-11",
-    "offset-md-0","offset-md-1","offset-md-2","offset-md-3","offset-md-4","offset-md-5",
-    "offset-md-6","offset-md-7","offset-md-8","offset-md-9","offset-md-10","offset-md-11",
-    "offset-lg-0","offset-lg-1","offset-lg-2","offset-lg-3","offset-lg-4","offset-lg-5",
-    "offset-lg-6","offset-lg-7","offset-lg-8","offset-lg-9","offset-lg-10","offset-lg-11",
-    "offset-xl-0","offset-xl-1","offset-xl-2","offset-xl-3","offset-xl-4","offset-xl-5",
-    "offset-xl-6","offset-xl-7","offset-xl-8","offset-xl-9","offset-xl-10","offset-xl-11",
-    "offset-xxl-0","offset-xxl-1","offset-xxl-2","offset-xxl-3","offset-xxl-4","offset-xxl-5",
-    "offset-xxl-6","offset-xxl-7","offset-xxl-8","offset-xxl-9","offset-xxl-10","offset-xxl-11",
+    "row","container","container-fluid",
     // State
     "active","disabled","show","fade","collapse","collapsing","collapse-horizontal",
     "is-valid","is-invalid","was-validated",
@@ -300,17 +468,13 @@ static STATIC: &[&str] = &[
     "list-unstyled","list-inline","list-inline-item",
     "img-fluid","img-thumbnail","figure","figure-img","figure-caption",
     // Buttons
-    "btn","btn-primary","btn-secondary","btn-success","btn-danger","btn-warning","btn-info",
-    "btn-light","btn-dark","btn-link",
-    "btn-outline-primary","btn-outline-secondary","btn-outline-success","btn-outline-danger",
-    "btn-outline-warning","btn-outline-info","btn-outline-light","btn-outline-dark",
+    "btn","btn-link",
     "btn-lg","btn-sm","btn-close","btn-close-white","btn-check",
     "btn-group","btn-group-lg","btn-group-sm","btn-group-vertical","btn-toolbar",
     // Accordion
     "accordion","accordion-item","accordion-header","accordion-button","accordion-body","accordion-flush",
     // Alert
-    "alert","alert-primary","alert-secondary","alert-success","alert-danger","alert-warning",
-    "alert-info","alert-light","alert-dark","alert-dismissible","alert-link","alert-heading",
+    "alert","alert-dismissible","alert-link","alert-heading",
     // Badge
     "badge",
     // Breadcrumb
@@ -326,10 +490,6 @@ static STATIC: &[&str] = &[
     // Dropdown
     "dropdown","dropdown-toggle","dropdown-toggle-split","dropdown-menu","dropdown-item",
     "dropdown-header","dropdown-divider","dropdown-item-text","dropdown-menu-dark","dropdown-center",
-    "dropdown-menu-start","dropdown-menu-end",
-    "dropdown-menu-sm-start","dropdown-menu-sm-end","dropdown-menu-md-start","dropdown-menu-md-end",
-    "dropdown-menu-lg-start","dropdown-menu-lg-end","dropdown-menu-xl-start","dropdown-menu-xl-end",
-    "dropdown-menu-xxl-start","dropdown-menu-xxl-end",
     "dropup","dropup-center","dropend","dropstart",
     // Forms
     "form-label","form-control","form-control-lg","form-control-sm","form-control-plaintext",
@@ -341,20 +501,16 @@ static STATIC: &[&str] = &[
     // Modal
     "modal","modal-dialog","modal-content","modal-header","modal-title","modal-body","modal-footer",
     "modal-sm","modal-lg","modal-xl","modal-fullscreen",
-    "modal-fullscreen-sm-down","modal-fullscreen-md-down","modal-fullscreen-lg-down",
-    "modal-fullscreen-xl-down","modal-fullscreen-xxl-down",
     "modal-dialog-scrollable","modal-dialog-centered","modal-backdrop","modal-open","modal-static",
     // Navbar
     "navbar","navbar-brand","navbar-toggler","navbar-toggler-icon","navbar-collapse",
     "navbar-nav","navbar-nav-scroll","navbar-text","navbar-dark","navbar-light",
-    "navbar-expand","navbar-expand-sm","navbar-expand-md","navbar-expand-lg","navbar-expand-xl","navbar-expand-xxl",
     // Nav / Tabs
     "nav","nav-link","nav-tabs","nav-pills","nav-fill","nav-justified","nav-underline",
     "tab-content","tab-pane",
     // Offcanvas
     "offcanvas","offcanvas-body","offcanvas-header","offcanvas-title",
     "offcanvas-start","offcanvas-end","offcanvas-top","offcanvas-bottom",
-    "offcanvas-sm","offcanvas-md","offcanvas-lg","offcanvas-xl","offcanvas-xxl",
     // Pagination
     "pagination","pagination-lg","pagination-sm","page-item","page-link",
     // Popover / Tooltip
@@ -369,8 +525,1076 @@ static STATIC: &[&str] = &[
     // Table
     "table","table-sm","table-bordered","table-borderless","table-striped","table-striped-columns",
     "table-hover","table-active","table-dark","table-group-divider",
-    "table-primary","table-secondary","table-success","table-danger","table-warning","table-info","table-light",
-    "table-responsive","table-responsive-sm","table-responsive-md","table-responsive-lg","table-responsive-xl","table-responsive-xxl",
     // Toast
     "toast","toast-header","toast-body","toast-container",
 ];
+
+// ── Bootstrap 4 ──────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled Bootstrap 4 list.
+pub const BOOTSTRAP4_NAME: &str = "Bootstrap 4.6";
+
+/// Bootstrap 4.6 default `$theme-colors`.
+static THEME_COLORS_4: &[(&str, &str)] = &[
+    ("primary", "#007bff"), ("secondary", "#6c757d"), ("success", "#28a745"),
+    ("info", "#17a2b8"), ("warning", "#ffc107"), ("danger", "#dc3545"),
+    ("light", "#f8f9fa"), ("dark", "#343a40"),
+];
+
+/// Returns all Bootstrap 4.6 class names (components + generated utilities).
+pub fn bootstrap4_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = STATIC_4.iter().map(|c| c.to_string()).collect();
+
+    let bp = ["", "sm-", "md-", "lg-", "xl-"];
+
+    // ── Grid ─────────────────────────────────────────────────────────────────
+    for b in bp {
+        s.insert(format!("col-{b}auto"));
+        s.insert(format!("row-cols-{b}auto"));
+        for v in 1..=12 {
+            s.insert(format!("col-{b}{v}"));
+            s.insert(format!("order-{b}{v}"));
+            s.insert(format!("offset-{b}{}", v - 1));
+        }
+        for v in 1..=6 {
+            s.insert(format!("row-cols-{b}{v}"));
+        }
+        for v in ["first","last","0"] {
+            s.insert(format!("order-{b}{v}"));
+        }
+    }
+    for b in ["sm", "md", "lg", "xl"] {
+        s.insert(format!("col-{b}"));
+        s.insert(format!("container-{b}"));
+        s.insert(format!("navbar-expand-{b}"));
+        s.insert(format!("table-responsive-{b}"));
+        s.insert(format!("dropdown-menu-{b}-left"));
+        s.insert(format!("dropdown-menu-{b}-right"));
+        s.insert(format!("list-group-horizontal-{b}"));
+    }
+
+    // ── Display ──────────────────────────────────────────────────────────────
+    for b in bp.iter().chain(["print-"].iter()) {
+        for v in ["none","inline","inline-block","block","table","table-row","table-cell",
+                  "flex","inline-flex"] {
+            s.insert(format!("d-{b}{v}"));
+        }
+    }
+
+    // ── Spacing m / p ────────────────────────────────────────────────────────
+    for prop in ["m", "p"] {
+        for dir in ["", "t", "b", "l", "r", "x", "y"] {
+            for b in bp {
+                for v in ["0","1","2","3","4","5","auto"] {
+                    s.insert(format!("{prop}{dir}-{b}{v}"));
+                }
+            }
+        }
+    }
+    for dir in ["", "t", "b", "l", "r", "x", "y"] {
+        for b in bp {
+            for v in ["1","2","3","4","5"] {
+                s.insert(format!("m{dir}-{b}n{v}"));
+            }
+        }
+    }
+
+    // ── Flex ─────────────────────────────────────────────────────────────────
+    for b in bp {
+        for v in ["row","row-reverse","column","column-reverse","wrap","wrap-reverse",
+                  "nowrap","fill","grow-0","grow-1","shrink-0","shrink-1"] {
+            s.insert(format!("flex-{b}{v}"));
+        }
+        for v in ["start","end","center","baseline","stretch"] {
+            s.insert(format!("align-items-{b}{v}"));
+        }
+        for v in ["start","end","center","between","around","stretch"] {
+            s.insert(format!("align-content-{b}{v}"));
+        }
+        for v in ["auto","start","end","center","baseline","stretch"] {
+            s.insert(format!("align-self-{b}{v}"));
+        }
+        for v in ["start","end","center","between","around"] {
+            s.insert(format!("justify-content-{b}{v}"));
+        }
+    }
+
+    // ── Float & text alignment ───────────────────────────────────────────────
+    for b in bp {
+        for v in ["left","right","none"] {
+            s.insert(format!("float-{b}{v}"));
+        }
+        for v in ["left","center","right"] {
+            s.insert(format!("text-{b}{v}"));
+        }
+    }
+
+    // ── Text ─────────────────────────────────────────────────────────────────
+    for v in ["justify","wrap","nowrap","truncate","break","lowercase","uppercase",
+              "capitalize","monospace","muted","white","black-50","white-50","body",
+              "reset","decoration-none","hide"] {
+        s.insert(format!("text-{v}"));
+    }
+    for v in ["bold","bolder","normal","light","lighter"] {
+        s.insert(format!("font-weight-{v}"));
+    }
+    s.insert("font-italic".into());
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for &(c, _) in THEME_COLORS_4 {
+        for prefix in ["text", "bg", "border", "btn", "btn-outline", "alert", "badge",
+                       "table", "list-group-item"] {
+            s.insert(format!("{prefix}-{c}"));
+        }
+        s.insert(format!("bg-gradient-{c}"));
+    }
+    for v in ["white","transparent"] {
+        s.insert(format!("bg-{v}"));
+        s.insert(format!("border-{v}"));
+    }
+
+    // ── Border ───────────────────────────────────────────────────────────────
+    s.insert("border".into());
+    for dir in ["top","right","bottom","left"] {
+        s.insert(format!("border-{dir}"));
+        s.insert(format!("border-{dir}-0"));
+        s.insert(format!("rounded-{dir}"));
+    }
+    s.insert("border-0".into());
+    for v in ["","-0","-sm","-lg","-circle","-pill"] {
+        s.insert(format!("rounded{v}"));
+    }
+
+    // ── Sizing, position, misc ───────────────────────────────────────────────
+    for v in ["25","50","75","100","auto"] {
+        s.insert(format!("w-{v}"));
+        s.insert(format!("h-{v}"));
+    }
+    for v in ["static","relative","absolute","fixed","sticky"] {
+        s.insert(format!("position-{v}"));
+    }
+    for v in ["","-sm","-lg","-none"] {
+        s.insert(format!("shadow{v}"));
+    }
+    for v in ["auto","hidden"] {
+        s.insert(format!("overflow-{v}"));
+    }
+    for v in ["all","auto","none"] {
+        s.insert(format!("user-select-{v}"));
+    }
+    for v in ["baseline","top","middle","bottom","text-top","text-bottom"] {
+        s.insert(format!("align-{v}"));
+    }
+    for v in ["21by9","16by9","4by3","1by1"] {
+        s.insert(format!("embed-responsive-{v}"));
+    }
+
+    s
+}
+
+/// Maps Bootstrap 4.6 color utilities and colored components to their color.
+pub fn bootstrap4_colors() -> HashMap<String, String> {
+    let mut m: HashMap<String, String> = HashMap::new();
+    for &(c, value) in THEME_COLORS_4 {
+        for prefix in ["text", "bg", "border", "btn", "btn-outline", "badge"] {
+            m.insert(format!("{prefix}-{c}"), value.into());
+        }
+    }
+    m.insert("text-white".into(), "#ffffff".into());
+    m.insert("bg-white".into(), "#ffffff".into());
+    m.insert("border-white".into(), "#ffffff".into());
+    m.insert("text-muted".into(), "#6c757d".into());
+    m.insert("text-body".into(), "#212529".into());
+    m.insert("bg-transparent".into(), "transparent".into());
+    m
+}
+
+static STATIC_4: &[&str] = &[
+    // Layout
+    "container","container-fluid","row","no-gutters","col","form-row",
+    "fixed-top","fixed-bottom","sticky-top","clearfix","visible","invisible",
+    "sr-only","sr-only-focusable","stretched-link","text-hide","embed-responsive",
+    "embed-responsive-item","mw-100","mh-100","min-vw-100","min-vh-100","vw-100","vh-100",
+    // Typography
+    "h1","h2","h3","h4","h5","h6","lead","mark","small","initialism","blockquote",
+    "blockquote-footer","display-1","display-2","display-3","display-4",
+    "list-unstyled","list-inline","list-inline-item","img-fluid","img-thumbnail",
+    "figure","figure-img","figure-caption",
+    // State
+    "active","disabled","show","fade","collapse","collapsing","is-valid","is-invalid",
+    "was-validated",
+    // Buttons
+    "btn","btn-link","btn-lg","btn-sm","btn-block","btn-group","btn-group-lg",
+    "btn-group-sm","btn-group-vertical","btn-group-toggle","btn-toolbar","close",
+    // Badge & alert
+    "badge","badge-pill","alert","alert-dismissible","alert-link","alert-heading",
+    // Breadcrumb
+    "breadcrumb","breadcrumb-item",
+    // Card
+    "card","card-body","card-title","card-subtitle","card-text","card-link","card-header",
+    "card-footer","card-img","card-img-top","card-img-bottom","card-img-overlay",
+    "card-group","card-deck","card-columns","card-header-tabs","card-header-pills",
+    // Carousel
+    "carousel","slide","carousel-inner","carousel-item","carousel-item-next",
+    "carousel-item-prev","carousel-fade","carousel-control-prev","carousel-control-next",
+    "carousel-control-prev-icon","carousel-control-next-icon","carousel-indicators",
+    "carousel-caption",
+    // Dropdown
+    "dropdown","dropdown-toggle","dropdown-toggle-split","dropdown-menu","dropdown-item",
+    "dropdown-header","dropdown-divider","dropdown-item-text","dropdown-menu-left",
+    "dropdown-menu-right","dropup","dropright","dropleft",
+    // Forms
+    "form-group","form-control","form-control-lg","form-control-sm","form-control-plaintext",
+    "form-control-file","form-control-range","form-text","form-inline","form-check",
+    "form-check-input","form-check-label","form-check-inline","col-form-label",
+    "col-form-label-lg","col-form-label-sm","custom-control","custom-control-input",
+    "custom-control-label","custom-control-inline","custom-checkbox","custom-radio",
+    "custom-switch","custom-select","custom-select-lg","custom-select-sm","custom-range",
+    "custom-file","custom-file-input","custom-file-label","input-group","input-group-lg",
+    "input-group-sm","input-group-prepend","input-group-append","input-group-text",
+    "invalid-feedback","valid-feedback","invalid-tooltip","valid-tooltip",
+    // Jumbotron & media
+    "jumbotron","jumbotron-fluid","media","media-body",
+    // List group
+    "list-group","list-group-item","list-group-item-action","list-group-flush",
+    "list-group-horizontal",
+    // Modal
+    "modal","modal-dialog","modal-content","modal-header","modal-title","modal-body",
+    "modal-footer","modal-sm","modal-lg","modal-xl","modal-dialog-scrollable",
+    "modal-dialog-centered","modal-backdrop","modal-open",
+    // Navbar & nav
+    "navbar","navbar-brand","navbar-toggler","navbar-toggler-icon","navbar-collapse",
+    "navbar-nav","navbar-text","navbar-dark","navbar-light","navbar-expand",
+    "nav","nav-item","nav-link","nav-tabs","nav-pills","nav-fill","nav-justified",
+    "tab-content","tab-pane",
+    // Pagination, popover, tooltip
+    "pagination","pagination-lg","pagination-sm","page-item","page-link",
+    "popover","popover-header","popover-body","bs-popover-top","bs-popover-right",
+    "bs-popover-bottom","bs-popover-left","bs-popover-auto","tooltip","tooltip-inner",
+    "bs-tooltip-top","bs-tooltip-right","bs-tooltip-bottom","bs-tooltip-left","bs-tooltip-auto",
+    // Progress & spinners
+    "progress","progress-bar","progress-bar-striped","progress-bar-animated",
+    "spinner-border","spinner-border-sm","spinner-grow","spinner-grow-sm",
+    // Table
+    "table","table-sm","table-bordered","table-borderless","table-striped","table-hover",
+    "table-active","table-dark","table-light","thead-dark","thead-light","table-responsive",
+    // Toast
+    "toast","toast-header","toast-body",
+];
+
+// ── Bootstrap 3 ──────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled Bootstrap 3 list.
+pub const BOOTSTRAP3_NAME: &str = "Bootstrap 3.4";
+
+/// Bootstrap 3.4 brand colors, and the contextual text/background colors
+/// that go with them.
+static THEME_COLORS_3: &[(&str, &str, &str, &str)] = &[
+    // name       brand      text       bg
+    ("primary", "#337ab7", "#337ab7", "#337ab7"),
+    ("success", "#5cb85c", "#3c763d", "#dff0d8"),
+    ("info",    "#5bc0de", "#31708f", "#d9edf7"),
+    ("warning", "#f0ad4e", "#8a6d3b", "#fcf8e3"),
+    ("danger",  "#d9534f", "#a94442", "#f2dede"),
+];
+
+/// Returns all Bootstrap 3.4 class names (components + generated grid).
+pub fn bootstrap3_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = STATIC_3.iter().map(|c| c.to_string()).collect();
+
+    let bp = ["xs", "sm", "md", "lg"];
+
+    // ── Grid ─────────────────────────────────────────────────────────────────
+    for b in bp {
+        for v in 1..=12 {
+            s.insert(format!("col-{b}-{v}"));
+        }
+        for v in 0..=12 {
+            s.insert(format!("col-{b}-offset-{v}"));
+            s.insert(format!("col-{b}-push-{v}"));
+            s.insert(format!("col-{b}-pull-{v}"));
+        }
+    }
+
+    // ── Responsive utilities ─────────────────────────────────────────────────
+    for b in bp {
+        s.insert(format!("hidden-{b}"));
+        s.insert(format!("visible-{b}"));
+        for v in ["block","inline","inline-block"] {
+            s.insert(format!("visible-{b}-{v}"));
+        }
+    }
+    for v in ["visible-print","visible-print-block","visible-print-inline",
+              "visible-print-inline-block","hidden-print"] {
+        s.insert(v.to_string());
+    }
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for &(c, ..) in THEME_COLORS_3 {
+        for prefix in ["text", "bg", "btn", "label", "alert", "panel", "progress-bar",
+                       "list-group-item"] {
+            s.insert(format!("{prefix}-{c}"));
+        }
+        s.insert(c.to_string());
+    }
+    s.remove("alert-primary");
+    s.remove("list-group-item-primary");
+    s.remove("progress-bar-primary");
+    s.remove("primary");
+
+    // ── Glyphicons ───────────────────────────────────────────────────────────
+    for v in GLYPHICONS {
+        s.insert(format!("glyphicon-{v}"));
+    }
+
+    s
+}
+
+/// Maps Bootstrap 3.4 contextual classes to the color they render with.
+pub fn bootstrap3_colors() -> HashMap<String, String> {
+    let mut m: HashMap<String, String> = HashMap::new();
+    for &(c, brand, text, bg) in THEME_COLORS_3 {
+        m.insert(format!("text-{c}"), text.into());
+        m.insert(format!("bg-{c}"), bg.into());
+        for prefix in ["btn", "label", "progress-bar"] {
+            m.insert(format!("{prefix}-{c}"), brand.into());
+        }
+    }
+    m.insert("text-muted".into(), "#777777".into());
+    m
+}
+
+static STATIC_3: &[&str] = &[
+    // Layout & helpers
+    "container","container-fluid","row","clearfix","pull-left","pull-right","center-block",
+    "show","hidden","invisible","sr-only","sr-only-focusable","text-hide","affix","caret",
+    "close","fade","in","collapse","collapsing","active","disabled",
+    // Typography
+    "h1","h2","h3","h4","h5","h6","lead","small","mark","text-left","text-center",
+    "text-right","text-justify","text-nowrap","text-lowercase","text-uppercase",
+    "text-capitalize","text-muted","page-header","initialism","blockquote-reverse",
+    "list-unstyled","list-inline","dl-horizontal","pre-scrollable",
+    // Images & embeds
+    "img-responsive","img-rounded","img-circle","img-thumbnail","embed-responsive",
+    "embed-responsive-16by9","embed-responsive-4by3","embed-responsive-item",
+    // Buttons
+    "btn","btn-default","btn-link","btn-lg","btn-sm","btn-xs","btn-block","btn-group",
+    "btn-group-lg","btn-group-sm","btn-group-xs","btn-group-vertical","btn-group-justified",
+    "btn-toolbar",
+    // Forms
+    "form-group","form-control","form-control-static","form-inline","form-horizontal",
+    "control-label","help-block","input-lg","input-sm","input-group","input-group-lg",
+    "input-group-sm","input-group-addon","input-group-btn","checkbox","radio",
+    "checkbox-inline","radio-inline","has-success","has-warning","has-error",
+    "has-feedback","form-control-feedback",
+    // Components
+    "glyphicon","dropdown","dropup","dropdown-toggle","dropdown-menu","dropdown-menu-right",
+    "dropdown-menu-left","dropdown-header","dropdown-backdrop","divider","nav","nav-tabs",
+    "nav-pills","nav-stacked","nav-justified","nav-divider","tab-content","tab-pane",
+    "navbar","navbar-default","navbar-inverse","navbar-header","navbar-brand",
+    "navbar-toggle","navbar-collapse","navbar-nav","navbar-form","navbar-btn","navbar-text",
+    "navbar-link","navbar-left","navbar-right","navbar-fixed-top","navbar-fixed-bottom",
+    "navbar-static-top","icon-bar","breadcrumb","pagination","pagination-lg",
+    "pagination-sm","pager","previous","next","label","label-default","badge","jumbotron",
+    "thumbnail","caption","alert","alert-dismissible","alert-link","progress",
+    "progress-bar","progress-bar-striped","media","media-body","media-left","media-right",
+    "media-middle","media-bottom","media-heading","media-list","list-group",
+    "list-group-item","list-group-item-heading","list-group-item-text","panel",
+    "panel-default","panel-heading","panel-title","panel-body","panel-footer",
+    "panel-group","panel-collapse","well","well-lg","well-sm","modal","modal-dialog",
+    "modal-content","modal-header","modal-title","modal-body","modal-footer","modal-lg",
+    "modal-sm","modal-backdrop","modal-open","tooltip","tooltip-inner","tooltip-arrow",
+    "popover","popover-title","popover-content","carousel","carousel-inner","item",
+    "carousel-control","carousel-indicators","carousel-caption","left","right",
+    // Tables
+    "table","table-striped","table-bordered","table-hover","table-condensed",
+    "table-responsive","success","info","warning","danger",
+];
+
+/// Glyphicon Halflings names bundled with Bootstrap 3.
+static GLYPHICONS: &[&str] = &[
+    "asterisk","plus","euro","eur","minus","cloud","envelope","pencil","glass","music",
+    "search","heart","star","star-empty","user","film","th-large","th","th-list","ok",
+    "remove","zoom-in","zoom-out","off","signal","cog","trash","home","file","time","road",
+    "download-alt","download","upload","inbox","play-circle","repeat","refresh","list-alt",
+    "lock","flag","headphones","volume-off","volume-down","volume-up","qrcode","barcode",
+    "tag","tags","book","bookmark","print","camera","font","bold","italic","text-height",
+    "text-width","align-left","align-center","align-right","align-justify","list",
+    "indent-left","indent-right","facetime-video","picture","map-marker","adjust","tint",
+    "edit","share","check","move","step-backward","fast-backward","backward","play",
+    "pause","stop","forward","fast-forward","step-forward","eject","chevron-left",
+    "chevron-right","plus-sign","minus-sign","remove-sign","ok-sign","question-sign",
+    "info-sign","screenshot","remove-circle","ok-circle","ban-circle","arrow-left",
+    "arrow-right","arrow-up","arrow-down","share-alt","resize-full","resize-small",
+    "exclamation-sign","gift","leaf","fire","eye-open","eye-close","warning-sign","plane",
+    "calendar","random","comment","magnet","chevron-up","chevron-down","retweet",
+    "shopping-cart","folder-close","folder-open","resize-vertical","resize-horizontal",
+    "hdd","bullhorn","bell","certificate","thumbs-up","thumbs-down","hand-right",
+    "hand-left","hand-up","hand-down","circle-arrow-right","circle-arrow-left",
+    "circle-arrow-up","circle-arrow-down","globe","wrench","tasks","filter","briefcase",
+    "fullscreen","dashboard","paperclip","heart-empty","link","phone","pushpin","usd","gbp",
+    "sort","sort-by-alphabet","sort-by-alphabet-alt","sort-by-order","sort-by-order-alt",
+    "sort-by-attributes","sort-by-attributes-alt","unchecked","expand","collapse-down",
+    "collapse-up","log-in","flash","log-out","new-window","record","save","open","saved",
+    "import","export","send","floppy-disk","floppy-saved","floppy-remove","floppy-save",
+    "floppy-open","credit-card","transfer","cutlery","header","compressed","earphone",
+    "phone-alt","tower","stats","sd-video","hd-video","subtitles","sound-stereo",
+    "sound-dolby","sound-5-1","sound-6-1","sound-7-1","copyright-mark","registration-mark",
+    "cloud-download","cloud-upload","tree-conifer","tree-deciduous","cd","save-file",
+    "open-file","level-up","copy","paste","alert","equalizer","king","queen","pawn","bishop",
+    "knight","baby-formula","tent","blackboard","bed","apple","erase","hourglass","lamp",
+    "duplicate","piggy-bank","scissors","bitcoin","btc","xbt","yen","jpy","ruble","rub",
+    "scale","ice-lolly","ice-lolly-tasted","education","option-horizontal",
+    "option-vertical","menu-hamburger","modal-window","oil","grain","sunglasses",
+    "text-size","text-color","text-background","object-align-top","object-align-bottom",
+    "object-align-horizontal","object-align-left","object-align-vertical",
+    "object-align-right","triangle-right","triangle-left","triangle-bottom","triangle-top",
+    "console","superscript","subscript","menu-left","menu-right","menu-down","menu-up",
+];
+
+// ── Bulma ────────────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled Bulma list.
+pub const BULMA_NAME: &str = "Bulma";
+
+/// The bundled Bulma vocabulary.
+pub fn bulma() -> Framework {
+    bundled(BULMA_NAME, bulma_classes(), bulma_colors())
+}
+
+/// Bulma's named colors, used by `has-text-*`, `has-background-*` and the
+/// `is-*` color modifiers of its components.
+static BULMA_COLORS: &[(&str, &str)] = &[
+    ("white", "#ffffff"), ("black", "#0a0a0a"), ("light", "#f5f5f5"), ("dark", "#363636"),
+    ("primary", "#00d1b2"), ("link", "#485fc7"), ("info", "#3e8ed0"),
+    ("success", "#48c78e"), ("warning", "#ffe08a"), ("danger", "#f14668"),
+];
+
+/// Bulma's grey shades, used by `has-text-*` and `has-background-*` only.
+static BULMA_SHADES: &[(&str, &str)] = &[
+    ("black-bis", "#121212"), ("black-ter", "#242424"), ("grey-darker", "#363636"),
+    ("grey-dark", "#4a4a4a"), ("grey", "#7a7a7a"), ("grey-light", "#b5b5b5"),
+    ("grey-lighter", "#dbdbdb"), ("white-ter", "#f5f5f5"), ("white-bis", "#fafafa"),
+];
+
+/// Returns all Bulma class names (components + generated helpers).
+pub fn bulma_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = BULMA_STATIC.iter().map(|c| c.to_string()).collect();
+
+    let bp = ["", "-mobile", "-tablet", "-touch", "-desktop", "-widescreen", "-fullhd"];
+    let only = ["-tablet-only", "-desktop-only", "-widescreen-only"];
+
+    // ── Columns ──────────────────────────────────────────────────────────────
+    for b in bp {
+        for v in ["1","2","3","4","5","6","7","8","9","10","11","12",
+                  "three-quarters","two-thirds","half","one-third","one-quarter","one-fifth",
+                  "two-fifths","three-fifths","four-fifths","full","narrow"] {
+            s.insert(format!("is-{v}{b}"));
+            s.insert(format!("is-offset-{v}{b}"));
+        }
+        // Column gap sizes, used together with `is-variable`
+        s.insert(format!("is-variable{b}"));
+        for v in ["0","1","2","3","4","5","6","7","8"] {
+            s.insert(format!("is-{v}{b}"));
+        }
+    }
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for &(c, _) in BULMA_COLORS.iter().chain(BULMA_SHADES) {
+        s.insert(format!("has-text-{c}"));
+        s.insert(format!("has-background-{c}"));
+    }
+    for &(c, _) in BULMA_COLORS {
+        s.insert(format!("is-{c}"));
+        for v in ["light","dark"] {
+            s.insert(format!("has-text-{c}-{v}"));
+            s.insert(format!("has-background-{c}-{v}"));
+        }
+    }
+
+    // ── Spacing ──────────────────────────────────────────────────────────────
+    for prop in ["m", "p"] {
+        for dir in ["", "t", "r", "b", "l", "x", "y"] {
+            for v in ["0","1","2","3","4","5","6"] {
+                s.insert(format!("{prop}{dir}-{v}"));
+            }
+        }
+    }
+    for dir in ["", "t", "r", "b", "l", "x", "y"] {
+        s.insert(format!("m{dir}-auto"));
+    }
+
+    // ── Typography ───────────────────────────────────────────────────────────
+    for b in bp.iter().chain(only.iter()) {
+        for v in ["1","2","3","4","5","6","7"] {
+            s.insert(format!("is-size-{v}{b}"));
+        }
+        for v in ["centered","justified","left","right"] {
+            s.insert(format!("has-text-{v}{b}"));
+        }
+    }
+    for v in ["light","normal","medium","semibold","bold","extrabold"] {
+        s.insert(format!("has-text-weight-{v}"));
+    }
+    for v in ["sans-serif","monospace","primary","secondary","code"] {
+        s.insert(format!("is-family-{v}"));
+    }
+
+    // ── Visibility ───────────────────────────────────────────────────────────
+    for b in bp.iter().chain(only.iter()) {
+        for v in ["block","flex","inline","inline-block","inline-flex","hidden"] {
+            s.insert(format!("is-{v}{b}"));
+        }
+    }
+
+    // ── Flexbox ──────────────────────────────────────────────────────────────
+    for v in ["row","row-reverse","column","column-reverse"] {
+        s.insert(format!("is-flex-direction-{v}"));
+    }
+    for v in ["nowrap","wrap","wrap-reverse"] {
+        s.insert(format!("is-flex-wrap-{v}"));
+    }
+    for v in ["flex-start","flex-end","center","space-between","space-around",
+              "space-evenly","start","end","left","right"] {
+        s.insert(format!("is-justify-content-{v}"));
+    }
+    for v in ["flex-start","flex-end","center","space-between","space-around",
+              "space-evenly","stretch","start","end","baseline"] {
+        s.insert(format!("is-align-content-{v}"));
+    }
+    for v in ["stretch","flex-start","flex-end","center","baseline","start","end",
+              "self-start","self-end"] {
+        s.insert(format!("is-align-items-{v}"));
+    }
+    for v in ["auto","flex-start","flex-end","center","baseline","stretch"] {
+        s.insert(format!("is-align-self-{v}"));
+    }
+    for v in ["0","1","2","3","4","5"] {
+        s.insert(format!("is-flex-grow-{v}"));
+        s.insert(format!("is-flex-shrink-{v}"));
+    }
+
+    s
+}
+
+/// Maps Bulma color helpers and colored components to the color they apply.
+pub fn bulma_colors() -> HashMap<String, String> {
+    let mut m: HashMap<String, String> = HashMap::new();
+    for &(c, value) in BULMA_COLORS.iter().chain(BULMA_SHADES) {
+        m.insert(format!("has-text-{c}"), value.into());
+        m.insert(format!("has-background-{c}"), value.into());
+    }
+    m
+}
+
+static BULMA_STATIC: &[&str] = &[
+    // Layout
+    "container","is-fluid","is-max-desktop","is-max-widescreen","section","hero","hero-body",
+    "hero-head","hero-foot","level","level-left","level-right","level-item","media",
+    "media-left","media-content","media-right","footer","tile","is-ancestor","is-parent",
+    "is-child","is-vertical","columns","column","is-multiline","is-centered","is-gapless",
+    "is-vcentered","is-mobile","is-desktop",
+    // Elements
+    "box","button","buttons","has-addons","content","delete","icon","icon-text","image",
+    "is-square","is-1by1","is-4by3","is-3by2","is-16by9","is-2by1","is-3by1","is-16x16",
+    "is-24x24","is-32x32","is-48x48","is-64x64","is-96x96","is-128x128","notification",
+    "progress","table","table-container","is-bordered","is-striped","is-narrow","is-hoverable",
+    "is-fullwidth","is-selected","tag","tags","title","subtitle","is-spaced","block",
+    // Form
+    "field","field-label","field-body","control","label","input","textarea","select",
+    "checkbox","radio","file","file-label","file-input","file-cta","file-icon","file-name",
+    "help","is-grouped","is-grouped-centered","is-grouped-right","is-grouped-multiline",
+    "is-expanded","has-icons-left","has-icons-right","is-horizontal","has-name","is-boxed",
+    // Components
+    "breadcrumb","has-arrow-separator","has-bullet-separator","has-dot-separator",
+    "has-succeeds-separator","card","card-header","card-header-title","card-header-icon",
+    "card-image","card-content","card-footer","card-footer-item","dropdown","dropdown-trigger",
+    "dropdown-menu","dropdown-content","dropdown-item","dropdown-divider","is-hoverable",
+    "is-up","menu","menu-label","menu-list","message","message-header","message-body",
+    "modal","modal-background","modal-content","modal-close","modal-card","modal-card-head",
+    "modal-card-title","modal-card-body","modal-card-foot","navbar","navbar-brand",
+    "navbar-burger","navbar-menu","navbar-start","navbar-end","navbar-item","navbar-link",
+    "navbar-dropdown","navbar-divider","has-dropdown","has-dropdown-up","is-fixed-top",
+    "is-fixed-bottom","is-transparent","is-arrowless","pagination","pagination-previous",
+    "pagination-next","pagination-list","pagination-link","pagination-ellipsis","is-current",
+    "panel","panel-heading","panel-tabs","panel-block","panel-icon","tabs","is-toggle",
+    "is-toggle-rounded",
+    // Modifiers
+    "is-small","is-normal","is-medium","is-large","is-outlined","is-inverted","is-rounded",
+    "is-hovered","is-focused","is-active","is-loading","is-static","is-text","is-ghost",
+    "is-light","is-dark","is-delete","is-fullheight","is-halfheight",
+    "is-fullheight-with-navbar",
+    // Helpers
+    "is-clearfix","is-pulled-left","is-pulled-right","is-overlay","is-clipped","is-radiusless",
+    "is-shadowless","is-unselectable","is-clickable","is-relative","is-sr-only","is-invisible",
+    "is-capitalized","is-lowercase","is-uppercase","is-italic","is-underlined",
+];
+
+// ── Foundation ───────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled Foundation list.
+pub const FOUNDATION_NAME: &str = "Foundation 6";
+
+/// The bundled Foundation 6 vocabulary.
+pub fn foundation() -> Framework {
+    bundled(FOUNDATION_NAME, foundation_classes(), foundation_colors())
+}
+
+/// Foundation's default `$foundation-palette`.
+static FOUNDATION_PALETTE: &[(&str, &str)] = &[
+    ("primary", "#1779ba"), ("secondary", "#767676"), ("success", "#3adb76"),
+    ("warning", "#ffae00"), ("alert", "#cc4b37"),
+];
+
+/// Returns all Foundation 6 class names (components + generated grid).
+pub fn foundation_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = FOUNDATION_STATIC.iter().map(|c| c.to_string()).collect();
+
+    let bp = ["small", "medium", "large", "xlarge", "xxlarge"];
+
+    // ── XY grid & float grid ─────────────────────────────────────────────────
+    for b in bp {
+        for v in 1..=12 {
+            s.insert(format!("{b}-{v}"));
+            s.insert(format!("{b}-offset-{}", v - 1));
+            s.insert(format!("{b}-push-{v}"));
+            s.insert(format!("{b}-pull-{v}"));
+            s.insert(format!("{b}-order-{v}"));
+        }
+        for v in 1..=8 {
+            s.insert(format!("{b}-up-{v}"));
+        }
+        for v in ["auto","shrink","full","centered","uncentered","collapse","uncollapse",
+                  "grid-frame","cell-block","cell-block-y","horizontal","vertical"] {
+            s.insert(format!("{b}-{v}"));
+        }
+        s.insert(format!("{b}-margin-collapse"));
+        s.insert(format!("{b}-padding-collapse"));
+        s.insert(format!("{b}-text-left"));
+        s.insert(format!("{b}-text-right"));
+        s.insert(format!("{b}-text-center"));
+        s.insert(format!("{b}-text-justify"));
+    }
+
+    // ── Visibility ───────────────────────────────────────────────────────────
+    for b in ["small", "medium", "large", "xlarge", "xxlarge"] {
+        s.insert(format!("show-for-{b}"));
+        s.insert(format!("show-for-{b}-only"));
+        s.insert(format!("hide-for-{b}"));
+        s.insert(format!("hide-for-{b}-only"));
+    }
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for &(c, _) in FOUNDATION_PALETTE {
+        s.insert(c.to_string());
+    }
+
+    s
+}
+
+/// Maps Foundation palette modifiers (`.button.primary`) to their color.
+pub fn foundation_colors() -> HashMap<String, String> {
+    FOUNDATION_PALETTE
+        .iter()
+        .map(|&(c, value)| (c.to_string(), value.to_string()))
+        .collect()
+}
+
+static FOUNDATION_STATIC: &[&str] = &[
+    // Grid
+    "grid-container","fluid","full","grid-x","grid-y","cell","auto","shrink",
+    "grid-margin-x","grid-margin-y","grid-padding-x","grid-padding-y","grid-frame",
+    "cell-block","cell-block-y","cell-block-container","align-left","align-right",
+    "align-center","align-justify","align-spaced","align-top","align-bottom","align-middle",
+    "align-stretch","align-self-top","align-self-bottom","align-self-middle",
+    "align-self-stretch","align-center-middle","row","column","columns","expanded","end",
+    "collapse",
+    // Typography & helpers
+    "lead","subheader","stat","no-bullet","text-left","text-right","text-center",
+    "text-justify","float-left","float-right","float-center","clearfix","hide","invisible",
+    "show-for-sr","show-on-focus","show-for-landscape","show-for-portrait","show-for-print",
+    "hide-for-print","thumbnail","hollow","clear","disabled","is-active","is-dropdown-submenu",
+    // Buttons & controls
+    "button","button-group","tiny","small","large","expanded","stacked","stacked-for-small",
+    "dropdown","arrow-only","close-button","switch","switch-input","switch-paddle",
+    "switch-active","switch-inactive","slider","slider-handle","slider-fill","input-group",
+    "input-group-label","input-group-field","input-group-button","help-text","form-error",
+    "is-invalid-input","is-invalid-label","is-visible","middle",
+    // Containers
+    "accordion","accordion-item","accordion-title","accordion-content","callout","card",
+    "card-divider","card-section","card-image","dropdown-pane","media-object",
+    "media-object-section","reveal","reveal-overlay","tabs","tabs-title","tabs-content",
+    "tabs-panel","off-canvas","off-canvas-wrapper","off-canvas-content","position-left",
+    "position-right","position-top","position-bottom","orbit","orbit-container",
+    "orbit-slide","orbit-bullets","responsive-embed","widescreen","flex-video","table-scroll",
+    "hover","unstriped","stack",
+    // Navigation
+    "menu","vertical","horizontal","simple","icons","icon-top","icon-left","nested",
+    "dropdown","drilldown","accordion-menu","menu-text","menu-centered","menu-icon",
+    "top-bar","top-bar-left","top-bar-right","title-bar","title-bar-left","title-bar-right",
+    "title-bar-title","breadcrumbs","pagination","pagination-previous","pagination-next",
+    "current","ellipsis","sticky","sticky-container",
+    // Media & feedback
+    "badge","label","progress","progress-meter","progress-meter-text","tooltip","has-tip",
+];
+
+// ── UIkit ────────────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled UIkit list.
+pub const UIKIT_NAME: &str = "UIkit 3";
+
+/// The bundled UIkit 3 vocabulary.
+pub fn uikit() -> Framework {
+    bundled(UIKIT_NAME, uikit_classes(), uikit_colors())
+}
+
+/// UIkit's default theme colors.
+static UIKIT_THEME: &[(&str, &str)] = &[
+    ("primary", "#1e87f0"), ("secondary", "#222222"), ("success", "#32d296"),
+    ("warning", "#faa05a"), ("danger", "#f0506e"), ("muted", "#999999"),
+];
+
+/// Returns all UIkit 3 class names (components + generated utilities).
+pub fn uikit_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = UIKIT_STATIC.iter().map(|c| c.to_string()).collect();
+
+    let bp = ["", "@s", "@m", "@l", "@xl"];
+    let fractions = ["1-1","1-2","1-3","2-3","1-4","3-4","1-5","2-5","3-5","4-5",
+                     "1-6","5-6","auto","expand"];
+
+    // ── Width ────────────────────────────────────────────────────────────────
+    for b in bp {
+        for v in fractions {
+            s.insert(format!("uk-width-{v}{b}"));
+            s.insert(format!("uk-child-width-{v}{b}"));
+        }
+        for v in ["small","medium","large","xlarge","2xlarge"] {
+            s.insert(format!("uk-width-{v}{b}"));
+        }
+        s.insert(format!("uk-visible{b}"));
+        s.insert(format!("uk-hidden{b}"));
+        for v in ["left","center","right"] {
+            s.insert(format!("uk-text-{v}{b}"));
+            s.insert(format!("uk-flex-{v}{b}"));
+        }
+        for v in ["first","last"] {
+            s.insert(format!("uk-flex-{v}{b}"));
+        }
+    }
+
+    // ── Margin & padding ─────────────────────────────────────────────────────
+    for size in ["", "-small", "-medium", "-large", "-xlarge", "-remove"] {
+        for side in ["", "-top", "-bottom", "-left", "-right", "-vertical"] {
+            s.insert(format!("uk-margin{size}{side}"));
+        }
+    }
+    for side in ["-top", "-bottom", "-left", "-right", "-vertical"] {
+        s.insert(format!("uk-margin-auto{side}"));
+    }
+    s.insert("uk-margin-auto".into());
+    for size in ["", "-small", "-large"] {
+        s.insert(format!("uk-padding{size}"));
+    }
+    for side in ["", "-top", "-bottom", "-left", "-right", "-vertical", "-horizontal"] {
+        s.insert(format!("uk-padding-remove{side}"));
+    }
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for &(c, _) in UIKIT_THEME {
+        s.insert(format!("uk-text-{c}"));
+        s.insert(format!("uk-background-{c}"));
+        s.insert(format!("uk-button-{c}"));
+        s.insert(format!("uk-card-{c}"));
+        s.insert(format!("uk-label-{c}"));
+        s.insert(format!("uk-alert-{c}"));
+    }
+
+    s
+}
+
+/// Maps UIkit color utilities to the color they apply.
+pub fn uikit_colors() -> HashMap<String, String> {
+    let mut m: HashMap<String, String> = HashMap::new();
+    for &(c, value) in UIKIT_THEME {
+        m.insert(format!("uk-text-{c}"), value.into());
+        m.insert(format!("uk-background-{c}"), value.into());
+    }
+    m
+}
+
+static UIKIT_STATIC: &[&str] = &[
+    // Layout
+    "uk-container","uk-container-xsmall","uk-container-small","uk-container-large",
+    "uk-container-xlarge","uk-container-expand","uk-section","uk-section-default",
+    "uk-section-muted","uk-section-primary","uk-section-secondary","uk-section-xsmall",
+    "uk-section-small","uk-section-large","uk-section-xlarge","uk-grid","uk-grid-small",
+    "uk-grid-medium","uk-grid-large","uk-grid-collapse","uk-grid-divider","uk-grid-match",
+    "uk-flex","uk-inline","uk-flex-inline","uk-flex-between","uk-flex-around","uk-flex-stretch",
+    "uk-flex-top","uk-flex-middle","uk-flex-bottom","uk-flex-row","uk-flex-column",
+    "uk-flex-wrap","uk-flex-nowrap","uk-flex-1","uk-flex-none","uk-flex-auto",
+    "uk-cover-container","uk-position-relative","uk-position-absolute","uk-position-fixed",
+    "uk-position-cover","uk-position-center","uk-position-top","uk-position-bottom",
+    "uk-position-left","uk-position-right","uk-position-small","uk-position-medium",
+    "uk-position-large","uk-position-z-index",
+    // Components
+    "uk-button","uk-button-default","uk-button-text","uk-button-link","uk-button-small",
+    "uk-button-large","uk-button-group","uk-card","uk-card-default","uk-card-body",
+    "uk-card-header","uk-card-footer","uk-card-title","uk-card-media-top","uk-card-hover",
+    "uk-card-small","uk-card-large","uk-card-badge","uk-alert","uk-alert-close","uk-label",
+    "uk-badge","uk-nav","uk-nav-default","uk-nav-primary","uk-nav-header","uk-nav-divider",
+    "uk-nav-sub","uk-navbar","uk-navbar-container","uk-navbar-left","uk-navbar-right",
+    "uk-navbar-center","uk-navbar-nav","uk-navbar-item","uk-navbar-toggle","uk-navbar-dropdown",
+    "uk-navbar-dropdown-nav","uk-subnav","uk-subnav-divider","uk-subnav-pill","uk-breadcrumb",
+    "uk-pagination","uk-tab","uk-tab-left","uk-tab-right","uk-dotnav","uk-thumbnav",
+    "uk-iconnav","uk-dropdown","uk-dropdown-nav","uk-modal","uk-modal-dialog","uk-modal-body",
+    "uk-modal-header","uk-modal-footer","uk-modal-title","uk-modal-close","uk-modal-close-default",
+    "uk-modal-container","uk-modal-full","uk-offcanvas","uk-offcanvas-bar","uk-offcanvas-close",
+    "uk-accordion","uk-accordion-title","uk-accordion-content","uk-list","uk-list-disc",
+    "uk-list-divider","uk-list-striped","uk-table","uk-table-divider","uk-table-striped",
+    "uk-table-hover","uk-table-small","uk-table-middle","uk-table-responsive","uk-overflow-auto",
+    "uk-form-stacked","uk-form-horizontal","uk-form-label","uk-form-controls","uk-input",
+    "uk-select","uk-textarea","uk-checkbox","uk-radio","uk-range","uk-form-small",
+    "uk-form-large","uk-form-width-xsmall","uk-form-width-small","uk-form-width-medium",
+    "uk-form-width-large","uk-form-danger","uk-form-success","uk-form-blank","uk-icon",
+    "uk-icon-button","uk-icon-link","uk-spinner","uk-progress","uk-slider","uk-slider-items",
+    "uk-slideshow","uk-slideshow-items","uk-lightbox","uk-sticky","uk-switcher","uk-tile",
+    "uk-tile-default","uk-tile-muted","uk-tile-primary","uk-tile-secondary","uk-overlay",
+    "uk-overlay-default","uk-overlay-primary","uk-article","uk-article-title","uk-article-meta",
+    "uk-comment","uk-comment-header","uk-comment-body","uk-comment-title","uk-comment-meta",
+    "uk-divider-icon","uk-divider-small","uk-divider-vertical","uk-heading-small",
+    "uk-heading-medium","uk-heading-large","uk-heading-xlarge","uk-heading-2xlarge",
+    "uk-heading-divider","uk-heading-bullet","uk-heading-line","uk-placeholder",
+    // Utilities
+    "uk-text-lead","uk-text-meta","uk-text-small","uk-text-default","uk-text-large",
+    "uk-text-light","uk-text-normal","uk-text-bold","uk-text-lighter","uk-text-bolder",
+    "uk-text-italic","uk-text-capitalize","uk-text-uppercase","uk-text-lowercase",
+    "uk-text-decoration-none","uk-text-justify","uk-text-top","uk-text-middle",
+    "uk-text-bottom","uk-text-baseline","uk-text-truncate","uk-text-break","uk-text-nowrap",
+    "uk-light","uk-dark","uk-border-rounded","uk-border-circle","uk-border-pill",
+    "uk-box-shadow-small","uk-box-shadow-medium","uk-box-shadow-large","uk-box-shadow-xlarge",
+    "uk-box-shadow-hover-small","uk-box-shadow-hover-medium","uk-box-shadow-hover-large",
+    "uk-background-default","uk-background-cover","uk-background-contain",
+    "uk-background-norepeat","uk-background-center-center","uk-height-1-1",
+    "uk-height-small","uk-height-medium","uk-height-large","uk-height-viewport",
+    "uk-height-match","uk-align-left","uk-align-right","uk-align-center","uk-float-left",
+    "uk-float-right","uk-clearfix","uk-display-block","uk-display-inline",
+    "uk-display-inline-block","uk-invisible","uk-hidden-hover","uk-invisible-hover",
+    "uk-responsive-width","uk-responsive-height","uk-active","uk-disabled","uk-open",
+    "uk-transition-toggle","uk-transition-fade","uk-transition-scale-up",
+    "uk-transition-slide-top","uk-transition-slide-bottom","uk-animation-fade",
+    "uk-animation-scale-up","uk-animation-slide-top","uk-animation-slide-bottom",
+    "uk-animation-toggle","uk-link-muted","uk-link-text","uk-link-heading","uk-link-reset",
+    "uk-logo","uk-inline-clip","uk-visible-toggle",
+];
+
+// ── Pico ─────────────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled Pico list.
+pub const PICO_NAME: &str = "Pico CSS";
+
+/// The bundled Pico CSS vocabulary. Pico styles semantic HTML, so its class
+/// list is small; the optional color utilities are included but their
+/// palette values are not bundled.
+pub fn pico() -> Framework {
+    bundled(PICO_NAME, pico_classes(), HashMap::new())
+}
+
+/// Returns all Pico CSS class names (layout helpers + `pico.colors.css`).
+pub fn pico_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = PICO_STATIC.iter().map(|c| c.to_string()).collect();
+
+    // ── Colors (pico.colors.css) ─────────────────────────────────────────────
+    for c in ["red","pink","fuchsia","purple","violet","indigo","blue","azure","cyan","jade",
+              "green","lime","yellow","amber","pumpkin","orange","sand","grey","zinc","slate"] {
+        s.insert(format!("pico-color-{c}"));
+        s.insert(format!("pico-background-{c}"));
+        for v in ["50","100","150","200","250","300","350","400","450","500",
+                  "550","600","650","700","750","800","850","900","950"] {
+            s.insert(format!("pico-color-{c}-{v}"));
+            s.insert(format!("pico-background-{c}-{v}"));
+        }
+    }
+
+    s
+}
+
+static PICO_STATIC: &[&str] = &[
+    "container","container-fluid","grid","overflow-auto","secondary","contrast","outline",
+    "pico","pico-background-black","pico-background-white","pico-color-black","pico-color-white",
+];
+
+// ── Tachyons ─────────────────────────────────────────────────────────────────
+
+/// Display name for classes that come from the bundled Tachyons list.
+pub const TACHYONS_NAME: &str = "Tachyons";
+
+/// The bundled Tachyons vocabulary.
+pub fn tachyons() -> Framework {
+    bundled(TACHYONS_NAME, tachyons_classes(), tachyons_colors())
+}
+
+/// Tachyons' default skin palette.
+static TACHYONS_SKINS: &[(&str, &str)] = &[
+    ("black", "#000000"), ("near-black", "#111111"), ("dark-gray", "#333333"),
+    ("mid-gray", "#555555"), ("gray", "#777777"), ("silver", "#999999"),
+    ("light-silver", "#aaaaaa"), ("moon-gray", "#cccccc"), ("light-gray", "#eeeeee"),
+    ("near-white", "#f4f4f4"), ("white", "#ffffff"), ("dark-red", "#e7040f"),
+    ("red", "#ff4136"), ("light-red", "#ff725c"), ("orange", "#ff6300"), ("gold", "#ffb700"),
+    ("yellow", "#ffd700"), ("light-yellow", "#fbf1a9"), ("purple", "#5e2ca5"),
+    ("light-purple", "#a463f2"), ("dark-pink", "#d5008f"), ("hot-pink", "#ff41b4"),
+    ("pink", "#ff80cc"), ("light-pink", "#ffa3d7"), ("dark-green", "#137752"),
+    ("green", "#19a974"), ("light-green", "#9eebcf"), ("navy", "#001b44"),
+    ("dark-blue", "#00449e"), ("blue", "#357edd"), ("light-blue", "#96ccff"),
+    ("lightest-blue", "#cdecff"), ("washed-blue", "#f6fffe"), ("washed-green", "#e8fdf5"),
+    ("washed-yellow", "#fffceb"), ("washed-red", "#ffdfdf"),
+];
+
+/// Returns all Tachyons class names (generated with the `-ns`/`-m`/`-l` media suffixes).
+pub fn tachyons_classes() -> HashSet<String> {
+    let mut s: HashSet<String> = HashSet::new();
+
+    let bp = ["", "-ns", "-m", "-l"];
+
+    for b in bp {
+        // ── Spacing ──────────────────────────────────────────────────────────
+        for prop in ["p", "m"] {
+            for dir in ["a", "h", "v", "t", "r", "b", "l"] {
+                for v in ["0","1","2","3","4","5","6","7"] {
+                    s.insert(format!("{prop}{dir}{v}{b}"));
+                }
+            }
+        }
+        for dir in ["a", "t", "r", "b", "l"] {
+            for v in ["1","2","3","4","5","6","7"] {
+                s.insert(format!("n{dir}{v}{b}"));
+            }
+        }
+
+        // ── Display & layout ─────────────────────────────────────────────────
+        for v in ["dn","di","db","dib","dit","dt","dtc","dt-row","dt--fixed","flex",
+                  "inline-flex","flex-auto","flex-none","flex-column","flex-row","flex-wrap",
+                  "flex-nowrap","items-start","items-end","items-center","items-baseline",
+                  "items-stretch","self-start","self-end","self-center","justify-start",
+                  "justify-end","justify-center","justify-between","justify-around",
+                  "content-start","content-end","content-center","content-between",
+                  "fl","fr","fn","cf","static","relative","absolute","fixed",
+                  "overflow-hidden","overflow-auto","overflow-visible","overflow-scroll",
+                  "cover","contain","center","top-0","right-0","bottom-0","left-0",
+                  "top-1","right-1","bottom-1","left-1","absolute--fill"] {
+            s.insert(format!("{v}{b}"));
+        }
+
+        // ── Typography ───────────────────────────────────────────────────────
+        for v in ["f1","f2","f3","f4","f5","f6","f7","f-headline","f-subheadline",
+                  "tl","tr","tc","tj","b","i","fs-normal","normal","ttc","ttl","ttu","ttn",
+                  "strike","underline","no-underline","lh-solid","lh-title","lh-copy",
+                  "tracked","tracked-tight","tracked-mega","measure","measure-wide",
+                  "measure-narrow","truncate","nowrap","pre","ws-normal","v-base","v-mid",
+                  "v-top","v-btm"] {
+            s.insert(format!("{v}{b}"));
+        }
+        for v in 1..=9 {
+            s.insert(format!("fw{v}{b}"));
+        }
+
+        // ── Sizing ───────────────────────────────────────────────────────────
+        for v in ["1","2","3","4","5","-10","-20","-25","-30","-33","-34","-40","-50",
+                  "-60","-70","-75","-80","-90","-100","-third","-two-thirds","-auto"] {
+            s.insert(format!("w{v}{b}"));
+        }
+        for v in ["1","2","3","4","5","-25","-50","-75","-100","-auto","-inherit"] {
+            s.insert(format!("h{v}{b}"));
+        }
+        for v in ["1","2","3","4","5","6","7","-100","-none"] {
+            s.insert(format!("mw{v}{b}"));
+        }
+        for v in ["vh-25","vh-50","vh-75","vh-100","min-vh-100","min-h-100"] {
+            s.insert(format!("{v}{b}"));
+        }
+
+        // ── Borders ──────────────────────────────────────────────────────────
+        for v in ["ba","bt","br","bb","bl","bn","br0","br1","br2","br3","br4","br-100",
+                  "br-pill","br--top","br--right","br--bottom","br--left","bw0","bw1","bw2",
+                  "bw3","bw4","bw5","b--dotted","b--dashed","b--solid","b--none"] {
+            s.insert(format!("{v}{b}"));
+        }
+    }
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for &(c, _) in TACHYONS_SKINS {
+        s.insert(c.to_string());
+        s.insert(format!("bg-{c}"));
+        s.insert(format!("b--{c}"));
+        s.insert(format!("hover-{c}"));
+        s.insert(format!("hover-bg-{c}"));
+    }
+    for v in ["black","white"] {
+        for a in ["90","80","70","60","50","40","30","20","10","05","025","0125"] {
+            s.insert(format!("{v}-{a}"));
+            s.insert(format!("bg-{v}-{a}"));
+            s.insert(format!("b--{v}-{a}"));
+        }
+    }
+
+    // ── Effects ──────────────────────────────────────────────────────────────
+    for v in ["o-100","o-90","o-80","o-70","o-60","o-50","o-40","o-30","o-20","o-10","o-05",
+              "o-0","shadow-1","shadow-2","shadow-3","shadow-4","shadow-5","dim","glow",
+              "grow","grow-large","pointer","shadow-hover","bg-animate","underline-hover",
+              "link","list","pl0","clip","z-0","z-1","z-2","z-3","z-4","z-5","z-999","z-9999",
+              "collapse","striped--light-gray","stripe-dark","sans-serif","serif","code",
+              "system-sans-serif","helvetica","avenir","athelas","georgia","times","bodoni",
+              "calisto","garamond","baskerville"] {
+        s.insert(v.to_string());
+    }
+
+    s
+}
+
+/// Maps Tachyons skin classes to the color they apply.
+pub fn tachyons_colors() -> HashMap<String, String> {
+    let mut m: HashMap<String, String> = HashMap::new();
+    for &(c, value) in TACHYONS_SKINS {
+        m.insert(c.to_string(), value.into());
+        m.insert(format!("bg-{c}"), value.into());
+        m.insert(format!("b--{c}"), value.into());
+    }
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_frameworks_by_id() {
+        for (id, name, class) in [
+            ("bulma", BULMA_NAME, "is-primary"),
+            ("foundation", FOUNDATION_NAME, "callout"),
+            ("uikit", UIKIT_NAME, "uk-button"),
+            ("pico", PICO_NAME, "pico-color-azure-500"),
+            ("tachyons", TACHYONS_NAME, "pa3"),
+        ] {
+            let framework = by_id(id).unwrap();
+            assert_eq!(framework.name, name);
+            assert!(framework.classes.contains(class), "{id}: {class}");
+            for color in framework.colors.keys() {
+                assert!(framework.classes.contains(color), "{id}: {color}");
+            }
+        }
+        assert!(by_id("bootstrap").is_none());
+        assert!(by_id("tailwind").is_none());
+    }
+
+    #[test]
+    fn bootstrap_versions_from_ids() {
+        assert_eq!(BootstrapVersion::from_id("bootstrap4"), Some(BootstrapVersion::V4));
+        assert_eq!(BootstrapVersion::from_id("bootstrap5"), Some(BootstrapVersion::V5));
+        assert_eq!(BootstrapVersion::from_id("bootstrap"), None);
+        assert_eq!(BootstrapVersion::from_id("bootstrap2"), None);
+        assert_eq!(BootstrapVersion::from_id("bulma"), None);
+        let theme = BootstrapTheme::default();
+        assert_eq!(bootstrap(BootstrapVersion::V3, &theme).name, BOOTSTRAP3_NAME);
+        assert!(bootstrap(BootstrapVersion::V4, &theme).classes.contains("ml-md-auto"));
+        assert!(bootstrap(BootstrapVersion::V5, &theme).classes.contains("ms-md-auto"));
+    }
+}
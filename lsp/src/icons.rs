@@ -0,0 +1,246 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::frameworks::Framework;
+use crate::scanner;
+use crate::settings::Settings;
+
+/// Display name for `bi-*` icon classes.
+pub const BOOTSTRAP_ICONS_NAME: &str = "Bootstrap Icons";
+
+/// Display name for `fa-*` icon classes.
+pub const FONT_AWESOME_NAME: &str = "Font Awesome";
+
+/// Where the icon font's stylesheet lives when installed with npm or composer.
+const BOOTSTRAP_ICONS_CSS: &[&str] = &[
+    "node_modules/bootstrap-icons/font/bootstrap-icons.css",
+    "vendor/twbs/bootstrap-icons/font/bootstrap-icons.css",
+];
+const FONT_AWESOME_CSS: &[&str] = &[
+    "node_modules/@fortawesome/fontawesome-free/css/all.css",
+    "node_modules/@fortawesome/fontawesome-free/css/fontawesome.css",
+    "node_modules/font-awesome/css/font-awesome.css",
+    "vendor/fortawesome/font-awesome/css/all.css",
+];
+
+/// Bootstrap Icons classes. Codepoints come from the installed or vendored
+/// `bootstrap-icons.css`; without one, a bundled list of common icon names is
+/// offered without codepoints, and other `bi-*` names are not reported.
+pub fn bootstrap_icons(roots: &[PathBuf], settings: &Settings) -> Framework {
+    let mut icons = Framework {
+        name: BOOTSTRAP_ICONS_NAME.to_string(),
+        ..Default::default()
+    };
+    icons.classes.insert("bi".to_string());
+    icons.classes.extend(BOOTSTRAP_ICONS.iter().map(|n| format!("bi-{n}")));
+    let found = stylesheets(roots, settings, BOOTSTRAP_ICONS_CSS, &["bootstrap-icons"]);
+    add_glyphs(&mut icons, &found, "bi-");
+    if found.is_empty() {
+        icons.partial_prefix = Some("bi-".to_string());
+    }
+    icons
+}
+
+/// Font Awesome classes: style, sizing and animation classes for versions 4
+/// to 6, plus icon names. As with Bootstrap Icons, codepoints come from an
+/// installed or vendored stylesheet.
+pub fn font_awesome(roots: &[PathBuf], settings: &Settings) -> Framework {
+    let mut icons = Framework {
+        name: FONT_AWESOME_NAME.to_string(),
+        ..Default::default()
+    };
+    icons.classes.extend(FONT_AWESOME_STATIC.iter().map(|c| c.to_string()));
+    icons.classes.extend(FONT_AWESOME_ICONS.iter().map(|n| format!("fa-{n}")));
+    let found = stylesheets(roots, settings, FONT_AWESOME_CSS, &["font-awesome", "fontawesome"]);
+    add_glyphs(&mut icons, &found, "fa-");
+    if found.is_empty() {
+        icons.partial_prefix = Some("fa-".to_string());
+    }
+    icons
+}
+
+/// The icon font stylesheets in the workspace: installed packages first,
+/// then vendored copies whose file name starts with one of `vendored`.
+fn stylesheets(
+    roots: &[PathBuf],
+    settings: &Settings,
+    installed: &[&str],
+    vendored: &[&str],
+) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    for root in roots {
+        found.extend(installed.iter().map(|p| root.join(p)).filter(|p| p.is_file()));
+        found.extend(scanner::find_css_files(root, settings).into_iter().filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| vendored.iter().any(|v| n.starts_with(v)))
+        }));
+    }
+    found
+}
+
+/// Adds every `prefix`-ed icon class an icon font stylesheet defines, with
+/// its codepoint as the class description.
+fn add_glyphs(icons: &mut Framework, paths: &[PathBuf], prefix: &str) {
+    for path in paths {
+        let Ok(css) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let glyphs = parse_glyphs(&css, prefix);
+        eprintln!("[css-class-lsp] {}: {} icon(s)", path.display(), glyphs.len());
+        for (class, codepoint) in glyphs {
+            icons.descriptions.insert(
+                class.clone(),
+                format!("Glyph `U+{codepoint:04X}` — CSS `content: \"\\{codepoint:x}\"`"),
+            );
+            icons.classes.insert(class);
+        }
+    }
+}
+
+/// Maps each `prefix`-ed class in an icon font stylesheet to its codepoint,
+/// from rules like `.bi-alarm::before { content: "\f102"; }` or Font
+/// Awesome 6's `.fa-house { --fa: "\f015"; }`.
+fn parse_glyphs(css: &str, prefix: &str) -> HashMap<String, u32> {
+    let rule_re = Regex::new(r"([^{}]+)\{([^}]*)\}").unwrap();
+    let content_re = Regex::new(r#"(?:content|--fa)\s*:\s*["']\\([0-9a-fA-F]{2,6})["']"#).unwrap();
+    let class_re = Regex::new(&format!(r"\.({}[a-zA-Z0-9_-]+)", regex::escape(prefix))).unwrap();
+
+    let mut glyphs = HashMap::new();
+    for rule in rule_re.captures_iter(css) {
+        let Some(codepoint) = content_re
+            .captures(&rule[2])
+            .and_then(|c| u32::from_str_radix(&c[1], 16).ok())
+        else {
+            continue;
+        };
+        for class in class_re.captures_iter(&rule[1]) {
+            glyphs.insert(class[1].to_string(), codepoint);
+        }
+    }
+    glyphs
+}
+
+// ── Font Awesome style & utility classes ─────────────────────────────────────
+static FONT_AWESOME_STATIC: &[&str] = &[
+    // Styles (v6, v5 shorthands, v4 base class)
+    "fa","fa-solid","fa-regular","fa-light","fa-thin","fa-duotone","fa-brands","fa-sharp",
+    "fa-sharp-solid","fa-sharp-regular","fa-sharp-light","fa-classic",
+    "fas","far","fal","fat","fad","fab",
+    // Sizing
+    "fa-2xs","fa-xs","fa-sm","fa-lg","fa-xl","fa-2xl",
+    "fa-1x","fa-2x","fa-3x","fa-4x","fa-5x","fa-6x","fa-7x","fa-8x","fa-9x","fa-10x",
+    "fa-fw","fa-ul","fa-li","fa-border","fa-pull-left","fa-pull-right","fa-inverse",
+    "fa-stack","fa-stack-1x","fa-stack-2x",
+    // Animation & rotation
+    "fa-spin","fa-spin-pulse","fa-spin-reverse","fa-pulse","fa-beat","fa-beat-fade","fa-bounce",
+    "fa-fade","fa-flip","fa-shake","fa-rotate-90","fa-rotate-180","fa-rotate-270",
+    "fa-rotate-by","fa-flip-horizontal","fa-flip-vertical","fa-flip-both",
+    "fa-swap-opacity","fa-sr-only","fa-sr-only-focusable",
+];
+
+// ── Common icon names (offered when no icon stylesheet is installed) ─────────
+static FONT_AWESOME_ICONS: &[&str] = &[
+    "house","magnifying-glass","user","users","gear","gears","trash","trash-can","pen",
+    "pen-to-square","pencil","check","xmark","plus","minus","bars","envelope","heart","star",
+    "bell","calendar","calendar-days","clock","download","upload","arrow-right","arrow-left",
+    "arrow-up","arrow-down","arrow-right-from-bracket","arrow-right-to-bracket",
+    "arrow-rotate-right","arrow-rotate-left","arrows-rotate","chevron-right","chevron-left",
+    "chevron-up","chevron-down","angle-right","angle-left","angle-up","angle-down",
+    "caret-right","caret-left","caret-up","caret-down","circle","circle-check",
+    "circle-xmark","circle-info","circle-question","circle-exclamation","circle-user",
+    "circle-plus","circle-minus","triangle-exclamation","info","question","exclamation",
+    "lock","lock-open","unlock","key","eye","eye-slash","phone","mobile","laptop","desktop",
+    "cart-shopping","bag-shopping","basket-shopping","credit-card","money-bill","wallet",
+    "image","images","camera","video","music","play","pause","stop","forward","backward",
+    "volume-high","volume-xmark","microphone","file","file-lines","file-pdf","file-image",
+    "file-arrow-down","folder","folder-open","link","share","share-nodes","copy","paste",
+    "scissors","filter","sort","sort-up","sort-down","list","list-ul","list-ol","table",
+    "table-cells","spinner","circle-notch","rotate","print","floppy-disk","paper-plane",
+    "inbox","comment","comments","message","reply","flag","bookmark","tag","tags","thumbs-up",
+    "thumbs-down","face-smile","globe","location-dot","map","map-location-dot","compass",
+    "car","truck","plane","bicycle","building","store","shop","briefcase","chart-line",
+    "chart-bar","chart-pie","database","server","cloud","cloud-arrow-up","cloud-arrow-down",
+    "code","terminal","bug","wrench","screwdriver-wrench","hammer","shield","shield-halved",
+    "fire","bolt","sun","moon","lightbulb","gift","trophy","award","graduation-cap","book",
+    "book-open","newspaper","clipboard","clipboard-check","sliders","toggle-on","toggle-off",
+    "power-off","right-from-bracket","right-to-bracket","up-right-from-square",
+    "expand","compress","maximize","minimize","ellipsis","ellipsis-vertical","grip",
+    "grip-vertical","hashtag","at","quote-left","quote-right","language","wifi","qrcode",
+    "barcode","calculator","hourglass","stopwatch","headphones","palette","brush",
+    "github","gitlab","twitter","x-twitter","facebook","facebook-f","instagram","linkedin",
+    "linkedin-in","youtube","tiktok","discord","slack","whatsapp","telegram","google",
+    "apple","windows","android","php","js","html5","css3","bootstrap","npm","docker",
+];
+
+static BOOTSTRAP_ICONS: &[&str] = &[
+    "activity","airplane","alarm","alarm-fill","apple","archive","arrow-bar-right",
+    "arrow-clockwise","arrow-counterclockwise","arrow-down","arrow-left","arrow-left-circle",
+    "arrow-repeat","arrow-return-left","arrow-right","arrow-right-circle","arrow-up",
+    "arrows-fullscreen","award","bag","bank","bar-chart","basket","battery","bell",
+    "bell-fill","bell-slash","bicycle","bluetooth","book","bookmark","bookmark-star","box",
+    "box-arrow-in-right","box-arrow-left","box-arrow-right","box-arrow-up-right","briefcase",
+    "brush","bug","building","calculator","calendar","calendar-check","calendar-event",
+    "calendar3","camera","caret-down","caret-down-fill","caret-left","caret-right","caret-up",
+    "caret-up-fill","cart","cart-plus","cash","chat","chat-dots","chat-left-text","check",
+    "check-circle","check-lg","check2","check2-all","check2-circle","chevron-down",
+    "chevron-left","chevron-right","chevron-up","circle","clipboard","clock","clock-history",
+    "cloud","cloud-arrow-down","cloud-arrow-up","cloud-download","cloud-upload","code",
+    "collection","compass","cpu","credit-card","cup-hot","currency-dollar","currency-euro",
+    "dash","dash-circle","dash-lg","dash-square","database","discord","display","download",
+    "emoji-smile","envelope","envelope-fill","envelope-open","exclamation",
+    "exclamation-circle","exclamation-triangle","eye","eye-slash","facebook","file-earmark",
+    "file-earmark-pdf","file-earmark-text","filter","flag","folder","fonts","fullscreen",
+    "fullscreen-exit","funnel","gear","gear-fill","geo-alt","gift","git","github","globe",
+    "google","graph-up","grid","grid-3x3-gap","hammer","hand-thumbs-down","hand-thumbs-up",
+    "hash","hdd","headphones","heart","heart-fill","hourglass","house","house-door",
+    "house-fill","image","inbox","info","info-circle","instagram","journal","justify","key",
+    "laptop","layers","lightbulb","lightning","link","link-45deg","linkedin","list",
+    "list-check","list-task","list-ul","lock","lock-fill","map","megaphone","mic","moon",
+    "music-note","palette","paperclip","patch-check","pause","pencil","pencil-square",
+    "people","people-fill","person","person-circle","person-fill","person-plus","person-x",
+    "phone","pie-chart","pin","play","plus","plus-circle","plus-lg","plus-square","power",
+    "printer","qr-code","question","question-circle","reply","search","send","share",
+    "shield","shield-check","shop","signpost","skip-backward","skip-forward","slack",
+    "slash-circle","sliders","sort-down","sort-up","speedometer2","stack","star","star-fill",
+    "stop-circle","stopwatch","sun","table","tag","telephone","terminal","text-center",
+    "text-left","text-right","three-dots","three-dots-vertical","toggle-off","toggle-on",
+    "tools","trash","trash3","trophy","truck","tv","twitter","type","unlock","upc-scan",
+    "upload","volume-mute","volume-up","wallet","whatsapp","wifi","window","windows","wrench",
+    "x","x-circle","x-lg","x-square","youtube","zoom-in","zoom-out",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glyphs_from_icon_font_stylesheets() {
+        let css = ".bi-alarm::before { content: \"\\f102\"; }\n\
+                   .bi-arrow-up-right::before, .bi-up::before { content: \"\\f144\" }\n\
+                   .fa-house { --fa: \"\\f015\"; }\n.bi { display: inline-block }";
+        let glyphs = parse_glyphs(css, "bi-");
+        assert_eq!(glyphs.len(), 3);
+        assert_eq!(glyphs["bi-arrow-up-right"], 0xf144);
+        assert_eq!(parse_glyphs(css, "fa-")["fa-house"], 0xf015);
+    }
+
+    #[test]
+    fn bundled_names_only_cover_part_of_the_prefix() {
+        let settings = Settings::default();
+        let icons = bootstrap_icons(&[], &settings);
+        assert_eq!(icons.partial_prefix.as_deref(), Some("bi-"));
+        assert_eq!(font_awesome(&[], &settings).partial_prefix.as_deref(), Some("fa-"));
+
+        let root = std::env::temp_dir().join(format!("css-class-lsp-icons-{}", std::process::id()));
+        let css = root.join("node_modules/bootstrap-icons/font/bootstrap-icons.css");
+        std::fs::create_dir_all(css.parent().unwrap()).unwrap();
+        std::fs::write(&css, ".bi-arrow-up-right::before { content: \"\\f144\"; }").unwrap();
+        let icons = bootstrap_icons(std::slice::from_ref(&root), &settings);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(icons.partial_prefix, None);
+        assert!(icons.classes.contains("bi-arrow-up-right"));
+        assert!(icons.descriptions["bi-arrow-up-right"].contains("U+F144"));
+    }
+}
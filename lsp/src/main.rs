@@ -1,9 +1,17 @@
+mod colors;
 mod context;
+mod detect;
+mod diagnostics;
 mod frameworks;
+mod icons;
+mod packs;
+mod sass;
 mod scanner;
+mod settings;
+mod tailwind;
 
 use std::collections::{BTreeSet, HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use tokio::sync::RwLock;
@@ -12,45 +20,663 @@ use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 use url::Url;
 
+use settings::Settings;
+
+/// Reports selectors in workspace stylesheets that no template references.
+const UNUSED_CLASSES_COMMAND: &str = "css-class-lsp.unusedClasses";
+
+/// Registration id of the workspace file watchers.
+const WATCHER_ID: &str = "css-file-watcher";
+
+/// Most "Create `.class` in …" actions offered for one undefined class.
+const MAX_CREATE_TARGETS: usize = 5;
+
+/// Directories holding third-party stylesheets, which new classes never go in.
+const VENDOR_DIRS: &[&str] = &["node_modules", "vendor", "bower_components", "third_party"];
+
 #[derive(Clone)]
 struct Backend {
     client: Client,
-    file_classes: Arc<RwLock<HashMap<Url, HashSet<String>>>>,
+    roots: Arc<RwLock<Vec<PathBuf>>>,
+    stylesheets: Arc<RwLock<HashMap<Url, scanner::Stylesheet>>>,
+    templates: Arc<RwLock<HashMap<Url, scanner::Template>>>,
+    frameworks: Arc<RwLock<Vec<frameworks::Framework>>>,
+    /// `initializationOptions` overlaid with the client's `css-class-lsp`
+    /// workspace configuration.
+    settings: Arc<RwLock<Settings>>,
+    /// The raw `initializationOptions`, which workspace configuration is
+    /// layered on.
+    init_options: Arc<RwLock<serde_json::Value>>,
+    /// Whether the client answers `workspace/configuration` requests.
+    pulls_configuration: Arc<RwLock<bool>>,
     documents: Arc<RwLock<HashMap<Url, String>>>,
+    /// Every class stylesheets and frameworks provide, rebuilt on first use
+    /// after either changes.
+    vocabulary: Arc<RwLock<Option<Arc<BTreeSet<String>>>>>,
+    /// Every class-like word in a workspace template, rebuilt on first use
+    /// after a template changes.
+    references: Arc<RwLock<Option<Arc<HashSet<String>>>>>,
+    /// The stylesheet last edited in the client, offered first for new classes.
+    last_stylesheet: Arc<RwLock<Option<Url>>>,
+    /// The `.scss` files that customize Bootstrap's Sass maps, so an edit
+    /// that removes the customization also reloads the frameworks.
+    bootstrap_overrides: Arc<RwLock<HashSet<PathBuf>>>,
 }
 
 impl Backend {
     fn new(client: Client) -> Self {
         Backend {
             client,
-            file_classes: Arc::new(RwLock::new(HashMap::new())),
+            roots: Arc::new(RwLock::new(Vec::new())),
+            stylesheets: Arc::new(RwLock::new(HashMap::new())),
+            templates: Arc::new(RwLock::new(HashMap::new())),
+            frameworks: Arc::new(RwLock::new(Vec::new())),
+            settings: Arc::new(RwLock::new(Settings::default())),
+            init_options: Arc::new(RwLock::new(serde_json::Value::Null)),
+            pulls_configuration: Arc::new(RwLock::new(false)),
             documents: Arc::new(RwLock::new(HashMap::new())),
+            vocabulary: Arc::new(RwLock::new(None)),
+            references: Arc::new(RwLock::new(None)),
+            last_stylesheet: Arc::new(RwLock::new(None)),
+            bootstrap_overrides: Arc::new(RwLock::new(HashSet::new())),
         }
     }
 
-    async fn all_classes(&self) -> BTreeSet<String> {
-        let mut classes: BTreeSet<String> =
-            frameworks::bootstrap5_classes().into_iter().collect();
-        let map = self.file_classes.read().await;
-        classes.extend(map.values().flatten().cloned());
+    async fn all_classes(&self) -> Arc<BTreeSet<String>> {
+        // Held while building, so a change made meanwhile clears the result
+        let mut cache = self.vocabulary.write().await;
+        if let Some(classes) = cache.as_ref() {
+            return classes.clone();
+        }
+        let mut classes: BTreeSet<String> = BTreeSet::new();
+        for framework in self.frameworks.read().await.iter() {
+            classes.extend(framework.classes.iter().cloned());
+        }
+        let map = self.stylesheets.read().await;
+        classes.extend(map.values().flat_map(|s| s.classes.iter().cloned()));
+        let classes = Arc::new(classes);
+        *cache = Some(classes.clone());
         classes
     }
 
-    async fn rescan_css_file(&self, path: PathBuf) {
-        if let Ok(content) = tokio::fs::read_to_string(&path).await {
-            if let Ok(url) = Url::from_file_path(&path) {
-                let classes = scanner::extract_classes(&content);
-                self.file_classes.write().await.insert(url, classes);
+    /// Drops the cached class vocabulary after a stylesheet or framework change.
+    async fn invalidate_vocabulary(&self) {
+        *self.vocabulary.write().await = None;
+    }
+
+    /// Drops the cached template references after a template change.
+    async fn invalidate_references(&self) {
+        *self.references.write().await = None;
+    }
+
+    /// Re-reads a changed file. Returns true if it can change which
+    /// frameworks apply (a Tailwind config, a package manifest, a template's
+    /// `<link>` tags, Bootstrap Sass overrides added or removed), in which
+    /// case the frameworks need reloading.
+    async fn rescan_file(&self, path: PathBuf) -> bool {
+        let Ok(content) = tokio::fs::read_to_string(&path).await else {
+            return false;
+        };
+        let settings = self.settings.read().await.clone();
+        let overrides = sass::is_bootstrap_override(&path, &content);
+        let overrode = {
+            let mut known = self.bootstrap_overrides.write().await;
+            if overrides {
+                !known.insert(path.clone())
+            } else {
+                known.remove(&path)
+            }
+        };
+        let mut links_changed = false;
+        if let Ok(url) = Url::from_file_path(&path) {
+            if settings.is_css_path(&path) {
+                let sheet = scanner::parse_stylesheet(&path, &content);
+                self.stylesheets.write().await.insert(url, sheet);
+                self.invalidate_vocabulary().await;
+            } else if settings.is_template_path(&path) {
+                let template = scanner::parse_template(&content, &settings);
+                let links = template.stylesheet_links.clone();
+                let previous = self.templates.write().await.insert(url, template);
+                self.invalidate_references().await;
+                links_changed = previous.map_or(!links.is_empty(), |t| t.stylesheet_links != links);
+            }
+        }
+        links_changed
+            || tailwind::is_config(&path, &content, &settings)
+            || detect::is_project_file(&path, &settings)
+            || packs::is_pack_path(&path)
+            || overrides
+            || overrode
+    }
+
+    async fn reload_frameworks(&self) {
+        let roots = self.roots.read().await.clone();
+        let settings = self.settings.read().await.clone();
+        let links: Vec<String> = self
+            .templates
+            .read()
+            .await
+            .values()
+            .flat_map(|t| t.stylesheet_links.iter().cloned())
+            .collect();
+        let load = move || load_frameworks(&roots, &settings, &links);
+        if let Ok(loaded) = tokio::task::spawn_blocking(load).await {
+            *self.frameworks.write().await = loaded;
+            self.invalidate_vocabulary().await;
+        }
+    }
+
+    /// Re-indexes every stylesheet and template in the workspace, then
+    /// reloads the frameworks and re-checks open documents.
+    async fn rescan_workspace(&self) {
+        let roots = self.roots.read().await.clone();
+        let settings = self.settings.read().await.clone();
+        let scan = tokio::task::spawn_blocking(move || {
+            let mut stylesheets = HashMap::new();
+            let mut templates = HashMap::new();
+            let mut overrides = HashSet::new();
+            for root in &roots {
+                let scanned = scanner::scan_directory(root, &settings);
+                let scanned_templates = scanner::scan_templates(root, &settings);
+                eprintln!(
+                    "[css-class-lsp] scanned {:?}: {} CSS file(s), {} template(s)",
+                    root,
+                    scanned.len(),
+                    scanned_templates.len()
+                );
+                stylesheets.extend(scanned);
+                templates.extend(scanned_templates);
+                overrides.extend(sass::bootstrap_overrides(root, &settings));
+            }
+            (stylesheets, templates, overrides)
+        });
+        if let Ok((stylesheets, templates, overrides)) = scan.await {
+            *self.stylesheets.write().await = stylesheets;
+            *self.templates.write().await = templates;
+            *self.bootstrap_overrides.write().await = overrides;
+            self.invalidate_references().await;
+            self.reload_frameworks().await;
+            // Documents opened during the scan were checked against a partial vocabulary
+            self.revalidate_open_documents().await;
+        }
+    }
+
+    /// The initialization options with the client's workspace configuration
+    /// layered over them.
+    async fn layered_settings(&self, config: &serde_json::Value) -> Settings {
+        let mut settings = Settings::default();
+        settings.update(&*self.init_options.read().await);
+        settings.update(config);
+        settings
+    }
+
+    /// Switches to the settings `config` gives, then rescans or reloads
+    /// whatever they affect.
+    async fn apply_configuration(&self, config: &serde_json::Value) {
+        let settings = self.layered_settings(config).await;
+        let previous = std::mem::replace(&mut *self.settings.write().await, settings.clone());
+        if previous == settings {
+            return;
+        }
+        eprintln!("[css-class-lsp] settings changed: {settings:?}");
+        if previous.trigger_characters != settings.trigger_characters {
+            eprintln!("[css-class-lsp] new trigger characters take effect after a restart");
+        }
+        if previous.needs_rescan(&settings) {
+            if previous.css_extensions != settings.css_extensions
+                || previous.template_extensions != settings.template_extensions
+            {
+                self.register_watchers(true).await;
+            }
+            self.rescan_workspace().await;
+        } else if previous.frameworks != settings.frameworks {
+            self.reload_frameworks().await;
+            self.revalidate_open_documents().await;
+        } else if previous.unused_classes != settings.unused_classes {
+            self.revalidate_open_documents().await;
+        }
+    }
+
+    /// Asks the client for the `css-class-lsp` configuration section.
+    async fn fetch_configuration(&self) -> Option<serde_json::Value> {
+        let item = ConfigurationItem {
+            scope_uri: None,
+            section: Some(settings::SECTION.to_string()),
+        };
+        match self.client.configuration(vec![item]).await {
+            Ok(values) => values.into_iter().next(),
+            Err(e) => {
+                eprintln!("[css-class-lsp] workspace/configuration failed: {e}");
+                None
+            }
+        }
+    }
+
+    /// Watches the workspace for changes to stylesheets and templates (with
+    /// the configured extensions) and to the files frameworks are detected
+    /// from. With `replace`, the previous registration is dropped first.
+    async fn register_watchers(&self, replace: bool) {
+        let settings = self.settings.read().await.clone();
+        if replace {
+            let unregistration = Unregistration {
+                id: WATCHER_ID.to_string(),
+                method: "workspace/didChangeWatchedFiles".to_string(),
+            };
+            if let Err(e) = self.client.unregister_capability(vec![unregistration]).await {
+                eprintln!("[css-class-lsp] watcher unregistration failed: {e}");
+            }
+        }
+        let watchers = vec![
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String(Settings::glob(&settings.css_extensions)),
+                kind: None,
+            },
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String(Settings::glob(&settings.template_extensions)),
+                kind: None,
+            },
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/tailwind.config.{js,cjs,mjs,ts}".to_string()),
+                kind: None,
+            },
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String(
+                    "**/{package.json,package-lock.json,yarn.lock,pnpm-lock.yaml,composer.json,composer.lock}"
+                        .to_string(),
+                ),
+                kind: None,
+            },
+            FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/.css-classes/*.{json,txt}".to_string()),
+                kind: None,
+            },
+        ];
+        let registration = Registration {
+            id: WATCHER_ID.to_string(),
+            method: "workspace/didChangeWatchedFiles".to_string(),
+            register_options: Some(
+                serde_json::to_value(DidChangeWatchedFilesRegistrationOptions { watchers })
+                    .unwrap(),
+            ),
+        };
+        if let Err(e) = self.client.register_capability(vec![registration]).await {
+            eprintln!("[css-class-lsp] watcher registration failed: {e}");
+        }
+    }
+
+    async fn current_settings(&self) -> Settings {
+        self.settings.read().await.clone()
+    }
+
+    /// Name of the first framework that provides `class`.
+    async fn framework_of(&self, class: &str) -> Option<String> {
+        let frameworks = self.frameworks.read().await;
+        frameworks
+            .iter()
+            .find(|f| f.classes.contains(class))
+            .map(|f| f.name.clone())
+    }
+
+    /// Every class-like word in any workspace template.
+    async fn referenced_classes(&self) -> Arc<HashSet<String>> {
+        let mut cache = self.references.write().await;
+        if let Some(words) = cache.as_ref() {
+            return words.clone();
+        }
+        let templates = self.templates.read().await;
+        let words: Arc<HashSet<String>> = Arc::new(
+            templates
+                .values()
+                .flat_map(|t| t.words.iter().cloned())
+                .collect(),
+        );
+        *cache = Some(words.clone());
+        words
+    }
+
+    /// Publishes diagnostics for an open document: undefined classes in
+    /// templates, classes no template uses in the project's own stylesheets
+    /// (unless the `unusedClasses` setting is off).
+    async fn validate(&self, uri: Url, known: &BTreeSet<String>, referenced: &HashSet<String>) {
+        let diagnostics = if self.is_css_url(&uri).await {
+            let sheets = self.stylesheets.read().await;
+            let Some(sheet) = sheets.get(&uri) else {
+                return;
+            };
+            if self.current_settings().await.unused_classes && !is_vendored(&uri) {
+                diagnostics::unused_classes(sheet, referenced)
+            } else {
+                Vec::new()
+            }
+        } else if self.is_template_url(&uri).await {
+            let Some(text) = self.documents.read().await.get(&uri).cloned() else {
+                return;
+            };
+            let frameworks = self.frameworks.read().await;
+            let partial: Vec<&str> =
+                frameworks.iter().filter_map(|f| f.partial_prefix.as_deref()).collect();
+            diagnostics::undefined_classes(&text, &self.current_settings().await, known, &partial)
+        } else {
+            return;
+        };
+        self.client
+            .publish_diagnostics(uri, diagnostics, None)
+            .await;
+    }
+
+    /// Re-checks every open document, e.g. after a stylesheet gained or lost a class.
+    async fn revalidate_open_documents(&self) {
+        let uris: Vec<Url> = self.documents.read().await.keys().cloned().collect();
+        let known = self.all_classes().await;
+        let referenced = self.referenced_classes().await;
+        for uri in uris {
+            self.validate(uri, &known, &referenced).await;
+        }
+    }
+
+    /// Re-checks the other open documents a change can affect, given the
+    /// vocabulary and references from before it: templates when the known
+    /// classes differ, stylesheets when templates use different words.
+    async fn revalidate_affected(
+        &self,
+        except: Option<&Url>,
+        known: &Arc<BTreeSet<String>>,
+        referenced: &Arc<HashSet<String>>,
+    ) {
+        let new_known = self.all_classes().await;
+        let new_referenced = self.referenced_classes().await;
+        let classes_changed = !Arc::ptr_eq(known, &new_known) && known != &new_known;
+        let words_changed =
+            !Arc::ptr_eq(referenced, &new_referenced) && referenced != &new_referenced;
+        if !classes_changed && !words_changed {
+            return;
+        }
+        let uris: Vec<Url> = self.documents.read().await.keys().cloned().collect();
+        for uri in uris {
+            if Some(&uri) == except {
+                continue;
+            }
+            let affected = if self.is_css_url(&uri).await {
+                words_changed
+            } else {
+                classes_changed
+            };
+            if affected {
+                self.validate(uri, &new_known, &new_referenced).await;
+            }
+        }
+    }
+
+    /// Re-indexes an open document and checks it, plus any other open
+    /// document whose diagnostics the edit changes.
+    async fn update_document(&self, uri: Url, text: String) {
+        let known = self.all_classes().await;
+        let referenced = self.referenced_classes().await;
+        if self.is_css_url(&uri).await {
+            let path = PathBuf::from(uri.path());
+            let sheet = scanner::parse_stylesheet(&path, &text);
+            let classes = sheet.classes.clone();
+            let previous = self.stylesheets.write().await.insert(uri.clone(), sheet);
+            if previous.is_none_or(|p| p.classes != classes) {
+                eprintln!("[css-class-lsp] CSS file updated, {} classes", classes.len());
+                self.invalidate_vocabulary().await;
+            }
+        } else if self.is_template_url(&uri).await {
+            let template = scanner::parse_template(&text, &self.current_settings().await);
+            let words = template.words.clone();
+            let previous = self.templates.write().await.insert(uri.clone(), template);
+            if previous.is_none_or(|p| p.words != words) {
+                self.invalidate_references().await;
+            }
+        }
+        self.documents.write().await.insert(uri.clone(), text);
+        let new_known = self.all_classes().await;
+        let new_referenced = self.referenced_classes().await;
+        self.validate(uri.clone(), &new_known, &new_referenced).await;
+        self.revalidate_affected(Some(&uri), &known, &referenced).await;
+    }
+
+    /// Finds the unused classes of every workspace stylesheet, minified and
+    /// vendored ones aside, and returns them as `{ class, uri, range }`
+    /// entries. Only open stylesheets show them as diagnostics, which
+    /// `validate` keeps current.
+    async fn unused_class_report(&self) -> Vec<serde_json::Value> {
+        let referenced = self.referenced_classes().await;
+        let reports: Vec<(Url, Vec<Diagnostic>)> = {
+            let sheets = self.stylesheets.read().await;
+            sheets
+                .iter()
+                .filter(|(url, _)| !is_vendored(url))
+                .map(|(url, sheet)| (url.clone(), diagnostics::unused_classes(sheet, &referenced)))
+                .collect()
+        };
+
+        let mut entries = Vec::new();
+        let mut files = 0;
+        for (url, diagnostics) in reports {
+            if !diagnostics.is_empty() {
+                files += 1;
+            }
+            for d in &diagnostics {
+                entries.push(serde_json::json!({
+                    "class": diagnostics::unused_class_name(d),
+                    "uri": url,
+                    "range": d.range,
+                }));
+            }
+        }
+        self.client
+            .show_message(
+                MessageType::INFO,
+                format!(
+                    "Found {} unused class selector(s) in {files} stylesheet(s)",
+                    entries.len()
+                ),
+            )
+            .await;
+        entries
+    }
+
+    /// Path of `url` relative to the workspace root it lives in, for display.
+    async fn display_path(&self, url: &Url) -> String {
+        relative_path(&self.roots.read().await, url)
+    }
+
+    /// The first declaration of each workspace class as `path:line`, for completion details.
+    async fn class_sources(&self) -> HashMap<String, String> {
+        let roots = self.roots.read().await;
+        let sheets = self.stylesheets.read().await;
+        let mut urls: Vec<&Url> = sheets.keys().collect();
+        urls.sort();
+
+        let mut sources = HashMap::new();
+        for url in urls {
+            let path = relative_path(&roots, url);
+            for selector in &sheets[url].selectors {
+                sources
+                    .entry(selector.name.clone())
+                    .or_insert_with(|| format!("{path}:{}", selector.range.start.line + 1));
+            }
+        }
+        sources
+    }
+
+    /// Every place a stylesheet in the workspace declares `class`, sorted by file.
+    async fn class_definitions(&self, class: &str) -> Vec<Location> {
+        let sheets = self.stylesheets.read().await;
+        let mut locations: Vec<Location> = sheets
+            .iter()
+            .flat_map(|(url, sheet)| {
+                sheet
+                    .selectors
+                    .iter()
+                    .filter(|s| s.name == class)
+                    .map(|s| Location::new(url.clone(), s.range))
+            })
+            .collect();
+        locations
+            .sort_by(|a, b| (a.uri.as_str(), a.range.start).cmp(&(b.uri.as_str(), b.range.start)));
+        locations
+    }
+
+    /// Markdown describing every rule that declares `class`, or None if unknown.
+    async fn describe_class(&self, class: &str) -> Option<String> {
+        let sheets = self.stylesheets.read().await;
+        let mut urls: Vec<&Url> = sheets.keys().collect();
+        urls.sort();
+
+        let mut sections = Vec::new();
+        for url in urls {
+            for rule in sheets[url].rules_for(class) {
+                let location = format!(
+                    "{}:{}",
+                    self.display_path(url).await,
+                    rule.range.start.line + 1
+                );
+                sections.push(format!("`{location}`\n```css\n{}\n```", format_rule(rule)));
             }
         }
+        if !sections.is_empty() {
+            return Some(sections.join("\n\n"));
+        }
+        let frameworks = self.frameworks.read().await;
+        let framework = frameworks.iter().find(|f| f.classes.contains(class))?;
+        let mut doc = format!("`.{class}` — {} built-in", framework.name);
+        if let Some(description) = framework.descriptions.get(class) {
+            doc.push_str("\n\n");
+            doc.push_str(description);
+        }
+        Some(doc)
     }
 
-    fn is_css_url(url: &Url) -> bool {
-        url.path()
-            .rsplit('.')
-            .next()
-            .map(|ext| matches!(ext, "css" | "scss" | "sass" | "less"))
-            .unwrap_or(false)
+    async fn is_css_url(&self, url: &Url) -> bool {
+        self.settings.read().await.is_css_path(Path::new(url.path()))
+    }
+
+    async fn is_template_url(&self, url: &Url) -> bool {
+        self.settings.read().await.is_template_path(Path::new(url.path()))
+    }
+
+    /// Every class attribute in a workspace template that uses `class`, sorted by file.
+    async fn class_usages(&self, class: &str) -> Vec<Location> {
+        let templates = self.templates.read().await;
+        let mut locations: Vec<Location> = templates
+            .iter()
+            .flat_map(|(url, template)| {
+                template
+                    .usages
+                    .iter()
+                    .filter(|u| u.name == class)
+                    .map(|u| Location::new(url.clone(), u.range))
+            })
+            .collect();
+        locations
+            .sort_by(|a, b| (a.uri.as_str(), a.range.start).cmp(&(b.uri.as_str(), b.range.start)));
+        locations
+    }
+
+    /// The class under the cursor, either in a stylesheet selector or in a class
+    /// attribute, with the range of the bare name (no leading `.`).
+    async fn class_at(&self, uri: &Url, pos: Position) -> Option<(String, Range)> {
+        if self.is_css_url(uri).await {
+            let sheets = self.stylesheets.read().await;
+            return sheets.get(uri)?.selectors.iter().find_map(|s| {
+                let hit = s.range.start <= pos && pos <= s.range.end;
+                (hit && !s.from_parent).then(|| (s.name.clone(), name_range(s.range)))
+            });
+        }
+
+        let text = self.get_document_text(uri).await?;
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let (class, start, end) =
+            context::get_word_at(&text, pos, &self.current_settings().await)?;
+        let lines = context::LineIndex::new(&text);
+        Some((
+            class,
+            Range::new(lines.position(start), lines.position(end)),
+        ))
+    }
+
+    /// The color each known class renders with: framework palettes, overridden
+    /// by colors declared in workspace stylesheets.
+    async fn class_colors(&self) -> HashMap<String, Color> {
+        let mut colors: HashMap<String, Color> = HashMap::new();
+        for framework in self.frameworks.read().await.iter() {
+            for (class, value) in &framework.colors {
+                if let Some(color) = colors::parse_color(value) {
+                    colors.entry(class.clone()).or_insert(color);
+                }
+            }
+        }
+        let sheets = self.stylesheets.read().await;
+        let mut urls: Vec<&Url> = sheets.keys().collect();
+        urls.sort();
+        for url in urls {
+            colors.extend(sheets[url].declared_colors());
+        }
+        colors
+    }
+
+    /// One "Create `.class` in <file>" action per workspace stylesheet.
+    async fn create_class_actions(
+        &self,
+        uri: &Url,
+        class: &str,
+        diagnostic: &Diagnostic,
+    ) -> Vec<CodeActionOrCommand> {
+        let links = match self.templates.read().await.get(uri) {
+            Some(template) => template.stylesheet_links.clone(),
+            None => Vec::new(),
+        };
+        let last = self.last_stylesheet.read().await.clone();
+        let sheets = self.stylesheets.read().await;
+        let mut targets = Vec::new();
+        for (url, sheet) in sheets.iter() {
+            if is_vendored(url) {
+                continue;
+            }
+            // Linked sheets first, in link order, then the last edited one
+            let rank = match links.iter().position(|href| links_to(href, url)) {
+                Some(i) => i,
+                None if last.as_ref() == Some(url) => links.len(),
+                None => links.len() + 1,
+            };
+            targets.push((rank, self.display_path(url).await, url, sheet));
+        }
+        targets.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+
+        targets
+            .into_iter()
+            .take(MAX_CREATE_TARGETS)
+            .map(|(_, path, url, sheet)| {
+                let edit = sheet.append_rule(class);
+                CodeActionOrCommand::CodeAction(CodeAction {
+                    title: format!("Create `.{class}` in {path}"),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit::new(HashMap::from([(
+                        url.clone(),
+                        vec![edit],
+                    )]))),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    /// Checks that `class` may be renamed, returning a user-facing reason if not.
+    async fn check_renamable(&self, class: &str) -> std::result::Result<(), String> {
+        if let Some(name) = self.framework_of(class).await {
+            return Err(format!(
+                "`.{class}` is a {name} built-in and cannot be renamed"
+            ));
+        }
+        if self.class_definitions(class).await.is_empty() {
+            return Err(format!(
+                "`.{class}` is not defined in any workspace stylesheet"
+            ));
+        }
+        Ok(())
     }
 
     /// Get document text from cache or fall back to reading from disk.
@@ -73,6 +699,154 @@ impl Backend {
     }
 }
 
+/// The framework vocabularies to offer: the chosen bundled frameworks (or
+/// those the project is detected to use, Tailwind included when a root has
+/// a Tailwind config), and the class packs in each root's `.css-classes`.
+fn load_frameworks(
+    roots: &[PathBuf],
+    settings: &Settings,
+    links: &[String],
+) -> Vec<frameworks::Framework> {
+    let theme = roots.iter().find_map(|root| tailwind::load_theme(root, settings));
+    let ids: Vec<String> = match &settings.frameworks {
+        Some(ids) => ids.clone(),
+        None => {
+            let mut detected: Vec<String> = Vec::new();
+            for id in roots.iter().flat_map(|root| detect::frameworks(root, links, settings)) {
+                if !detected.contains(&id) {
+                    detected.push(id);
+                }
+            }
+            if theme.is_some() {
+                detected.push(tailwind::ID.to_string());
+            }
+            detected
+        }
+    };
+
+    let mut loaded = Vec::new();
+    for id in &ids {
+        let framework = match id.as_str() {
+            "bootstrap" | "bootstrap3" | "bootstrap4" | "bootstrap5" => {
+                let version = frameworks::BootstrapVersion::from_id(id)
+                    .or_else(|| {
+                        roots
+                            .iter()
+                            .find_map(|root| detect::bootstrap_version(root, links, settings))
+                    })
+                    .unwrap_or(frameworks::BootstrapVersion::V5);
+                eprintln!("[css-class-lsp] bootstrap version: {version:?}");
+                let theme = roots
+                    .iter()
+                    .find_map(|root| sass::bootstrap_theme(root, settings))
+                    .unwrap_or_default();
+                Some(frameworks::bootstrap(version, &theme))
+            }
+            "bootstrap-icons" => Some(icons::bootstrap_icons(roots, settings)),
+            "font-awesome" => Some(icons::font_awesome(roots, settings)),
+            // Pinned without a config, the default theme applies
+            tailwind::ID => Some(tailwind::framework(&theme.clone().unwrap_or_default())),
+            id => frameworks::by_id(id),
+        };
+        match framework {
+            Some(framework) => loaded.push(framework),
+            None => eprintln!("[css-class-lsp] unknown framework: {id}"),
+        }
+    }
+    for root in roots {
+        loaded.extend(packs::load(root));
+    }
+    loaded
+}
+
+/// Path of `url` relative to the workspace root it lives in, for display.
+fn relative_path(roots: &[PathBuf], url: &Url) -> String {
+    let Ok(path) = url.to_file_path() else {
+        return url.to_string();
+    };
+    roots
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Whether `url` is a minified or third-party stylesheet.
+fn is_vendored(url: &Url) -> bool {
+    let Ok(path) = url.to_file_path() else {
+        return false;
+    };
+    let minified = path
+        .file_name()
+        .is_some_and(|n| n.to_string_lossy().contains(".min."));
+    minified
+        || path
+            .components()
+            .any(|c| VENDOR_DIRS.iter().any(|d| c.as_os_str() == *d))
+}
+
+/// Whether a `<link href>` points at `url`: the href's path, without `.`
+/// and `..` segments, is a suffix of the stylesheet's path.
+fn links_to(href: &str, url: &Url) -> bool {
+    if href.contains("://") || href.starts_with("//") {
+        return false;
+    }
+    let Ok(path) = url.to_file_path() else {
+        return false;
+    };
+    let href = href.split(['?', '#']).next().unwrap_or_default();
+    let suffix: PathBuf = href
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != "." && *s != "..")
+        .collect();
+    !suffix.as_os_str().is_empty() && path.ends_with(suffix)
+}
+
+/// Escapes text for literal use inside an LSP snippet.
+fn escape_snippet(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('$', "\\$")
+        .replace('}', "\\}")
+}
+
+/// The range of a selector's class name without its leading `.`.
+fn name_range(selector: Range) -> Range {
+    let mut range = selector;
+    range.start.character += 1;
+    range
+}
+
+/// True if `class` appears in `text` as a whole class name (not part of a longer one).
+fn contains_class(text: &str, class: &str) -> bool {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    text.match_indices(class).any(|(i, _)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + class.len()..].chars().next();
+        !before.is_some_and(is_name_char) && !after.is_some_and(is_name_char)
+    })
+}
+
+/// Renders a rule with its at-rule wrappers, e.g. for hover popups.
+fn format_rule(rule: &scanner::CssRule) -> String {
+    let mut out = String::new();
+    let mut indent = String::new();
+    for at in &rule.at_rules {
+        out.push_str(&format!("{indent}{at} {{\n"));
+        indent.push_str("  ");
+    }
+    out.push_str(&format!("{indent}{} {{\n", rule.selector));
+    for decl in &rule.declarations {
+        out.push_str(&format!("{indent}  {decl};\n"));
+    }
+    out.push_str(&format!("{indent}}}"));
+    for _ in &rule.at_rules {
+        indent.truncate(indent.len() - 2);
+        out.push_str(&format!("\n{indent}}}"));
+    }
+    out
+}
+
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
@@ -97,19 +871,21 @@ impl LanguageServer for Backend {
         };
 
         eprintln!("[css-class-lsp] initialize, roots={:?}", roots);
+        *self.roots.write().await = roots;
 
-        let file_classes = self.file_classes.clone();
-        tokio::task::spawn_blocking(move || {
-            for root in &roots {
-                let scanned = scanner::scan_directory(root);
-                eprintln!(
-                    "[css-class-lsp] scanned {:?}: {} CSS file(s)",
-                    root,
-                    scanned.len()
-                );
-                file_classes.blocking_write().extend(scanned);
-            }
-        });
+        // initializationOptions: { "frameworks": ["bulma", "foundation"], "skipDirs": [...] }
+        let init_options = params.initialization_options.unwrap_or_default();
+        let mut settings = Settings::default();
+        settings.update(&init_options);
+        eprintln!("[css-class-lsp] settings: {settings:?}");
+        *self.settings.write().await = settings.clone();
+        *self.init_options.write().await = init_options;
+        *self.pulls_configuration.write().await = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|w| w.configuration)
+            .unwrap_or(false);
 
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
@@ -117,21 +893,28 @@ impl LanguageServer for Backend {
                     TextDocumentSyncKind::FULL,
                 )),
                 completion_provider: Some(CompletionOptions {
-                    trigger_characters: Some(vec![
-                        " ".to_string(), "\"".to_string(), "'".to_string(), "-".to_string(),
-                        "a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(),
-                        "e".to_string(), "f".to_string(), "g".to_string(), "h".to_string(),
-                        "i".to_string(), "j".to_string(), "k".to_string(), "l".to_string(),
-                        "m".to_string(), "n".to_string(), "o".to_string(), "p".to_string(),
-                        "q".to_string(), "r".to_string(), "s".to_string(), "t".to_string(),
-                        "u".to_string(), "v".to_string(), "w".to_string(), "x".to_string(),
-                        "y".to_string(), "z".to_string(),
-                        "0".to_string(), "1".to_string(), "2".to_string(), "3".to_string(),
-                        "4".to_string(), "5".to_string(), "6".to_string(), "7".to_string(),
-                        "8".to_string(), "9".to_string(),
-                    ]),
+                    trigger_characters: Some(settings.trigger_characters),
+                    resolve_provider: Some(true),
                     ..Default::default()
                 }),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                color_provider: Some(ColorProviderCapability::Simple(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        ..Default::default()
+                    },
+                )),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![UNUSED_CLASSES_COMMAND.to_string()],
+                    ..Default::default()
+                }),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 ..Default::default()
             },
             server_info: Some(ServerInfo {
@@ -143,20 +926,35 @@ impl LanguageServer for Backend {
 
     async fn initialized(&self, _: InitializedParams) {
         eprintln!("[css-class-lsp] initialized");
-        let watchers = vec![FileSystemWatcher {
-            glob_pattern: GlobPattern::String("**/*.{css,scss,sass,less}".to_string()),
-            kind: None,
-        }];
-        let registration = Registration {
-            id: "css-file-watcher".to_string(),
-            method: "workspace/didChangeWatchedFiles".to_string(),
-            register_options: Some(
-                serde_json::to_value(DidChangeWatchedFilesRegistrationOptions { watchers })
-                    .unwrap(),
-            ),
+        // Requests to the client are answered only after this handler
+        // returns, and other responses wait on it meanwhile, so the set-up
+        // that needs the client runs in the background
+        let backend = self.clone();
+        tokio::spawn(async move {
+            // The workspace configuration applies before the first scan
+            if *backend.pulls_configuration.read().await {
+                if let Some(config) = backend.fetch_configuration().await {
+                    let settings = backend.layered_settings(&config).await;
+                    *backend.settings.write().await = settings;
+                }
+            }
+            tokio::join!(backend.rescan_workspace(), backend.register_watchers(false));
+        });
+    }
+
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        // Clients that support `workspace/configuration` are asked for the
+        // settings; others push them, possibly under the section name.
+        let config = if *self.pulls_configuration.read().await {
+            self.fetch_configuration().await
+        } else {
+            match params.settings.get(settings::SECTION) {
+                Some(section) => Some(section.clone()),
+                None => Some(params.settings),
+            }
         };
-        if let Err(e) = self.client.register_capability(vec![registration]).await {
-            eprintln!("[css-class-lsp] watcher registration failed: {e}");
+        if let Some(config) = config {
+            self.apply_configuration(&config).await;
         }
     }
 
@@ -168,49 +966,80 @@ impl LanguageServer for Backend {
         let uri = params.text_document.uri;
         let text = params.text_document.text;
         eprintln!("[css-class-lsp] did_open: {uri}");
-        if Self::is_css_url(&uri) {
-            let classes = scanner::extract_classes(&text);
-            eprintln!("[css-class-lsp] CSS file opened, {} classes", classes.len());
-            self.file_classes.write().await.insert(uri, classes);
-        } else {
-            self.documents.write().await.insert(uri, text);
-        }
+        self.update_document(uri, text).await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = params.text_document.uri;
         if let Some(change) = params.content_changes.into_iter().last() {
             let text = change.text;
-            if Self::is_css_url(&uri) {
-                let classes = scanner::extract_classes(&text);
-                self.file_classes.write().await.insert(uri, classes);
-            } else {
-                self.documents.write().await.insert(uri, text);
+            if self.is_css_url(&uri).await {
+                *self.last_stylesheet.write().await = Some(uri.clone());
             }
+            self.update_document(uri, text).await;
         }
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
-        self.documents
-            .write()
-            .await
-            .remove(&params.text_document.uri);
+        let uri = params.text_document.uri;
+        let known = self.all_classes().await;
+        let referenced = self.referenced_classes().await;
+        self.documents.write().await.remove(&uri);
+        if self.is_css_url(&uri).await || self.is_template_url(&uri).await {
+            self.client.publish_diagnostics(uri.clone(), Vec::new(), None).await;
+        }
+
+        // The index holds the buffer as last edited: go back to the saved
+        // file, or forget a buffer that was never saved.
+        match uri.to_file_path().ok().filter(|path| path.is_file()) {
+            Some(path) => {
+                if self.rescan_file(path).await {
+                    self.reload_frameworks().await;
+                }
+            }
+            None => {
+                if self.stylesheets.write().await.remove(&uri).is_some() {
+                    self.invalidate_vocabulary().await;
+                }
+                if self.templates.write().await.remove(&uri).is_some() {
+                    self.invalidate_references().await;
+                }
+            }
+        }
+        self.revalidate_affected(None, &known, &referenced).await;
     }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let known = self.all_classes().await;
+        let referenced = self.referenced_classes().await;
+        let mut frameworks_changed = false;
         for change in params.changes {
+            let Ok(path) = change.uri.to_file_path() else {
+                continue;
+            };
             match change.typ {
                 FileChangeType::CREATED | FileChangeType::CHANGED => {
-                    if let Ok(path) = change.uri.to_file_path() {
-                        self.rescan_css_file(path).await;
-                    }
+                    frameworks_changed |= self.rescan_file(path).await;
                 }
                 FileChangeType::DELETED => {
-                    self.file_classes.write().await.remove(&change.uri);
+                    self.stylesheets.write().await.remove(&change.uri);
+                    self.templates.write().await.remove(&change.uri);
+                    self.bootstrap_overrides.write().await.remove(&path);
+                    self.invalidate_vocabulary().await;
+                    self.invalidate_references().await;
+                    let settings = self.settings.read().await;
+                    frameworks_changed |= tailwind::is_config(&path, "", &settings)
+                        || detect::is_project_file(&path, &settings)
+                        || packs::is_pack_path(&path)
+                        || settings.is_css_path(&path);
                 }
                 _ => {}
             }
         }
+        if frameworks_changed {
+            self.reload_frameworks().await;
+        }
+        self.revalidate_affected(None, &known, &referenced).await;
     }
 
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
@@ -233,28 +1062,372 @@ impl LanguageServer for Backend {
         // Normalize line endings so position math is always correct
         let text = text.replace("\r\n", "\n").replace('\r', "\n");
 
-        if !context::is_in_class_attribute(&text, pos) {
+        let settings = self.current_settings().await;
+        if !context::is_in_class_context(&text, pos, &settings) {
             eprintln!("[css-class-lsp] not in class attribute");
             return Ok(None);
         }
 
-        let prefix = context::get_word_prefix(&text, pos);
+        let Some((prefix, start, end)) = context::get_utility_prefix(&text, pos, &settings) else {
+            return Ok(None);
+        };
         eprintln!("[css-class-lsp] prefix={:?}", prefix);
+        // `clsx({ act| })` completes to `{ "active-link" }`
+        let bare_key = context::bare_key_at(&text, pos, &settings);
+
+        // Only the final segment is replaced, so `md:hover:fl` keeps its variants.
+        let lines = context::LineIndex::new(&text);
+        let range = Range::new(lines.position(start), lines.position(end));
+        let edit = |new_text: String| Some(CompletionTextEdit::Edit(TextEdit { range, new_text }));
 
         let all = self.all_classes().await;
-        let items: Vec<CompletionItem> = all
+        let mut sources = self.class_sources().await;
+        let frameworks = self.frameworks.read().await;
+        for framework in frameworks.iter() {
+            for class in &framework.classes {
+                if !sources.contains_key(class) {
+                    let detail = match framework.categories.get(class) {
+                        Some(category) => format!("{} · {category}", framework.name),
+                        None => framework.name.clone(),
+                    };
+                    sources.insert(class.clone(), detail);
+                }
+            }
+        }
+        let mut items: Vec<CompletionItem> = all
             .iter()
             .filter(|c| c.starts_with(&prefix))
             .map(|c| CompletionItem {
                 label: c.clone(),
                 kind: Some(CompletionItemKind::VALUE),
+                detail: sources.get(c).cloned(),
+                text_edit: edit(match bare_key {
+                    Some(quote) => context::object_key(c, quote),
+                    None => c.clone(),
+                }),
+                data: Some(serde_json::Value::String(c.clone())),
                 ..Default::default()
             })
             .collect();
 
+        // An object key names one class: no variant chains or arbitrary values
+        let extras = if bare_key.is_none() { frameworks.as_slice() } else { &[] };
+        for framework in extras {
+            if !prefix.contains('[') {
+                for variant in framework.variants.iter().filter(|v| v.starts_with(&prefix)) {
+                    items.push(CompletionItem {
+                        label: format!("{variant}:"),
+                        kind: Some(CompletionItemKind::KEYWORD),
+                        detail: Some(format!("{} variant", framework.name)),
+                        text_edit: edit(format!("{variant}:")),
+                        ..Default::default()
+                    });
+                }
+            }
+            for (utility, example) in &framework.arbitrary {
+                let open = format!("{utility}-[");
+                let value = if let Some(typed) = prefix.strip_prefix(&open) {
+                    typed
+                } else if prefix.contains('-') && open.starts_with(&prefix) {
+                    ""
+                } else {
+                    continue;
+                };
+                let (label, snippet) = if value.is_empty() {
+                    (format!("{open}{example}]"), format!("{open}${{1:{example}}}]"))
+                } else {
+                    (format!("{open}{value}]"), format!("{open}{}]$0", escape_snippet(value)))
+                };
+                items.push(CompletionItem {
+                    label,
+                    kind: Some(CompletionItemKind::SNIPPET),
+                    detail: Some(format!("{} arbitrary value", framework.name)),
+                    insert_text_format: Some(InsertTextFormat::SNIPPET),
+                    text_edit: edit(snippet),
+                    ..Default::default()
+                });
+            }
+        }
+
         eprintln!("[css-class-lsp] returning {} items", items.len());
         Ok(Some(CompletionResponse::Array(items)))
     }
+
+    async fn completion_resolve(&self, mut item: CompletionItem) -> Result<CompletionItem> {
+        let class = match &item.data {
+            Some(serde_json::Value::String(class)) => class.clone(),
+            _ => return Ok(item),
+        };
+        if let Some(markdown) = self.describe_class(&class).await {
+            item.documentation = Some(Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: markdown,
+            }));
+        }
+        Ok(item)
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let pos = params.text_document_position_params.position;
+
+        let Some(text) = self.get_document_text(&uri).await else {
+            return Ok(None);
+        };
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+        let settings = self.current_settings().await;
+        let Some((class, start, end)) = context::get_word_at(&text, pos, &settings) else {
+            return Ok(None);
+        };
+        eprintln!("[css-class-lsp] hover: {class}");
+
+        let Some(markdown) = self.describe_class(&class).await else {
+            return Ok(None);
+        };
+        let lines = context::LineIndex::new(&text);
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: markdown,
+            }),
+            range: Some(Range::new(lines.position(start), lines.position(end))),
+        }))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let pos = params.text_document_position_params.position;
+
+        let Some((class, _)) = self.class_at(&uri, pos).await else {
+            return Ok(None);
+        };
+
+        let locations = self.class_definitions(&class).await;
+        eprintln!(
+            "[css-class-lsp] definition: {class} -> {} location(s)",
+            locations.len()
+        );
+        if locations.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(GotoDefinitionResponse::Array(locations)))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = params.text_document_position.text_document.uri;
+        let pos = params.text_document_position.position;
+
+        let Some((class, _)) = self.class_at(&uri, pos).await else {
+            return Ok(None);
+        };
+
+        let mut locations = self.class_usages(&class).await;
+        if params.context.include_declaration {
+            locations.extend(self.class_definitions(&class).await);
+        }
+        eprintln!(
+            "[css-class-lsp] references: {class} -> {} location(s)",
+            locations.len()
+        );
+        Ok(Some(locations))
+    }
+
+    async fn document_color(&self, params: DocumentColorParams) -> Result<Vec<ColorInformation>> {
+        let uri = params.text_document.uri;
+        if !self.is_template_url(&uri).await {
+            return Ok(Vec::new());
+        }
+        let Some(text) = self.get_document_text(&uri).await else {
+            return Ok(Vec::new());
+        };
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+        let colors = self.class_colors().await;
+        let lines = context::LineIndex::new(&text);
+        let settings = self.current_settings().await;
+        Ok(context::class_tokens(&text, &settings)
+            .into_iter()
+            .filter_map(|t| {
+                Some(ColorInformation {
+                    range: Range::new(lines.position(t.start), lines.position(t.end)),
+                    color: *colors.get(&t.name)?,
+                })
+            })
+            .collect())
+    }
+
+    async fn color_presentation(
+        &self,
+        _: ColorPresentationParams,
+    ) -> Result<Vec<ColorPresentation>> {
+        // Swatches are read-only: a class name cannot be rewritten from a picked color.
+        Ok(Vec::new())
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = params.text_document.uri;
+        let Some(text) = self.get_document_text(&uri).await else {
+            return Ok(None);
+        };
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let known = self.all_classes().await;
+
+        let mut actions = Vec::new();
+        for diagnostic in params.context.diagnostics {
+            if !diagnostics::is_undefined_class(&diagnostic)
+                || diagnostic.range.end < params.range.start
+                || diagnostic.range.start > params.range.end
+            {
+                continue;
+            }
+            let start = context::position_to_offset(&text, diagnostic.range.start);
+            let end = context::position_to_offset(&text, diagnostic.range.end);
+            let Some(class) = start.zip(end).and_then(|(s, e)| text.get(s..e)) else {
+                continue;
+            };
+
+            for (i, suggestion) in diagnostics::closest_matches(class, &known)
+                .into_iter()
+                .enumerate()
+            {
+                let edit = TextEdit::new(diagnostic.range, suggestion.clone());
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: format!("Replace with `{suggestion}`"),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit::new(HashMap::from([(
+                        uri.clone(),
+                        vec![edit],
+                    )]))),
+                    is_preferred: Some(i == 0),
+                    ..Default::default()
+                }));
+            }
+            actions.extend(self.create_class_actions(&uri, class, &diagnostic).await);
+        }
+        Ok(Some(actions))
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        match params.command.as_str() {
+            UNUSED_CLASSES_COMMAND => {
+                let report = self.unused_class_report().await;
+                Ok(Some(serde_json::Value::Array(report)))
+            }
+            other => Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "unknown command `{other}`"
+            ))),
+        }
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let Some((class, range)) = self
+            .class_at(&params.text_document.uri, params.position)
+            .await
+        else {
+            return Ok(None);
+        };
+        self.check_renamable(&class)
+            .await
+            .map_err(tower_lsp::jsonrpc::Error::invalid_params)?;
+        Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range,
+            placeholder: class,
+        }))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let pos = params.text_document_position.position;
+
+        let Some((class, _)) = self.class_at(&uri, pos).await else {
+            return Ok(None);
+        };
+        self.check_renamable(&class)
+            .await
+            .map_err(tower_lsp::jsonrpc::Error::invalid_params)?;
+
+        let new_name = params.new_name.trim().trim_start_matches('.');
+        if !context::is_class_name(new_name) {
+            return Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                "`{new_name}` is not a valid class name"
+            )));
+        }
+
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        let mut skipped: Vec<(Url, Range)> = Vec::new();
+
+        for (url, sheet) in self.stylesheets.read().await.iter() {
+            for selector in sheet.selectors.iter().filter(|s| s.name == class) {
+                if selector.from_parent {
+                    skipped.push((url.clone(), selector.range));
+                } else {
+                    let edit = TextEdit::new(name_range(selector.range), new_name.to_string());
+                    changes.entry(url.clone()).or_default().push(edit);
+                }
+            }
+            // `@extend .old;` would stop matching anything
+            for (_, range) in sheet.extends.iter().filter(|(name, _)| *name == class) {
+                let edit = TextEdit::new(name_range(*range), new_name.to_string());
+                changes.entry(url.clone()).or_default().push(edit);
+            }
+        }
+
+        for (url, template) in self.templates.read().await.iter() {
+            for usage in template.usages.iter().filter(|u| u.name == class) {
+                // `{ active: on }` becomes `{ "is-active": on }`
+                let new_text = match usage.bare_key {
+                    Some(quote) => context::object_key(new_name, quote),
+                    None => new_name.to_string(),
+                };
+                let edit = TextEdit::new(usage.range, new_text);
+                changes.entry(url.clone()).or_default().push(edit);
+            }
+            // Names edited above are blanked out of the dynamic values; report
+            // a value when the name still shows up in an expression.
+            for value in &template.dynamic {
+                if contains_class(&value.text, &class) {
+                    skipped.push((url.clone(), value.range));
+                }
+            }
+        }
+
+        eprintln!(
+            "[css-class-lsp] rename: {class} -> {new_name}, {} file(s), {} skipped",
+            changes.len(),
+            skipped.len()
+        );
+        if !skipped.is_empty() {
+            let mut locations = Vec::new();
+            for (url, range) in &skipped {
+                locations.push(format!(
+                    "{}:{}",
+                    self.display_path(url).await,
+                    range.start.line + 1
+                ));
+            }
+            let message = format!(
+                "Renamed `.{class}` to `.{new_name}`, but {} usage(s) could not be edited safely: {}",
+                skipped.len(),
+                locations.join(", ")
+            );
+            self.client
+                .show_message(MessageType::WARNING, message)
+                .await;
+        }
+
+        Ok(Some(WorkspaceEdit::new(changes)))
+    }
 }
 
 #[tokio::main]
@@ -265,3 +1438,391 @@ async fn main() {
     let (service, socket) = LspService::new(Backend::new);
     Server::new(stdin, stdout, socket).serve(service).await;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(path: &str) -> Url {
+        Url::from_file_path(format!("/ws/{path}")).unwrap()
+    }
+
+    /// A server whose workspace holds `files` (path, content), all open.
+    async fn workspace(files: &[(&str, &str)]) -> Backend {
+        let (service, _) = LspService::new(Backend::new);
+        let backend = service.inner().clone();
+        *backend.roots.write().await = vec![PathBuf::from("/ws")];
+        for (path, text) in files {
+            backend.update_document(url(path), text.to_string()).await;
+        }
+        backend
+    }
+
+    /// The position of the first `needle` in `path`, moved `offset` chars in.
+    async fn at(
+        backend: &Backend,
+        path: &str,
+        needle: &str,
+        offset: u32,
+    ) -> TextDocumentPositionParams {
+        let text = backend.documents.read().await[&url(path)].clone();
+        let start = text.find(needle).unwrap();
+        let mut position = context::LineIndex::new(&text).position(start);
+        position.character += offset;
+        TextDocumentPositionParams::new(TextDocumentIdentifier::new(url(path)), position)
+    }
+
+    /// Locations as `path:line:character`, for compact assertions.
+    fn spots(locations: &[Location]) -> Vec<String> {
+        locations
+            .iter()
+            .map(|l| {
+                let path = l.uri.path().trim_start_matches("/ws/");
+                format!("{path}:{}:{}", l.range.start.line, l.range.start.character)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn definition_lists_every_declaring_selector() {
+        let backend = workspace(&[
+            ("b.css", ".card { }\n.card-body { }"),
+            ("a.scss", ".list {\n  .card { }\n}"),
+            ("index.html", r#"<div class="card card-body"></div>"#),
+        ])
+        .await;
+        let position = at(&backend, "index.html", "card", 2).await;
+        let params = GotoDefinitionParams {
+            text_document_position_params: position,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let Some(GotoDefinitionResponse::Array(locations)) =
+            backend.goto_definition(params).await.unwrap()
+        else {
+            panic!("no definition");
+        };
+        assert_eq!(spots(&locations), ["a.scss:1:2", "b.css:0:0"]);
+    }
+
+    #[tokio::test]
+    async fn definition_of_an_unknown_class_is_none() {
+        let backend = workspace(&[("index.html", r#"<div class="missing"></div>"#)]).await;
+        let params = GotoDefinitionParams {
+            text_document_position_params: at(&backend, "index.html", "missing", 1).await,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        assert!(backend.goto_definition(params).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn references_cover_templates_and_selectors() {
+        let backend = workspace(&[
+            ("app.css", ".btn { }\n.btn-lg { }"),
+            ("a.html", r#"<a class="btn btn-lg"></a>"#),
+            ("b.tsx", r#"<b className={clsx("x", { btn: on })} />"#),
+        ])
+        .await;
+        let references = |include_declaration| {
+            let backend = backend.clone();
+            async move {
+                let params = ReferenceParams {
+                    text_document_position: at(&backend, "app.css", ".btn", 2).await,
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                    context: ReferenceContext { include_declaration },
+                };
+                spots(&backend.references(params).await.unwrap().unwrap())
+            }
+        };
+        assert_eq!(references(false).await, ["a.html:0:10", "b.tsx:0:26"]);
+        assert_eq!(references(true).await, ["a.html:0:10", "b.tsx:0:26", "app.css:0:0"]);
+    }
+
+    /// Renames the class at `needle` in `path`, returning the edits as
+    /// `path:line:character new_text`, sorted.
+    async fn rename_edits(backend: &Backend, path: &str, needle: &str, to: &str) -> Vec<String> {
+        let params = RenameParams {
+            text_document_position: at(backend, path, needle, 1).await,
+            new_name: to.to_string(),
+            work_done_progress_params: Default::default(),
+        };
+        let edit = backend.rename(params).await.unwrap().unwrap();
+        let mut edits: Vec<String> = edit
+            .changes
+            .unwrap()
+            .iter()
+            .flat_map(|(url, edits)| {
+                let path = url.path().trim_start_matches("/ws/").to_string();
+                edits.iter().map(move |e| {
+                    let start = e.range.start;
+                    format!("{path}:{}:{} {}", start.line, start.character, e.new_text)
+                })
+            })
+            .collect();
+        edits.sort();
+        edits
+    }
+
+    #[tokio::test]
+    async fn rename_edits_selectors_extends_and_usages() {
+        let backend = workspace(&[
+            ("app.scss", ".btn {\n  &-lg { }\n}\n.btn-lg.wide { }\n.big { @extend .btn-lg; }"),
+            ("a.html", r#"<a class="btn-lg {{ big ? 'btn-lg' : '' }}"></a>"#),
+            ("b.tsx", r#"<b className={clsx({ active: on, "btn-lg": big })} />"#),
+            ("c.css", ".active { }"),
+        ])
+        .await;
+        // `&-lg` and the `{{ }}` expression are reported, not edited
+        assert_eq!(
+            rename_edits(&backend, "a.html", "btn-lg", ".btn-large").await,
+            [
+                "a.html:0:10 btn-large",
+                "app.scss:3:1 btn-large",
+                "app.scss:4:16 btn-large",
+                "b.tsx:0:34 btn-large",
+            ]
+        );
+        assert_eq!(
+            rename_edits(&backend, "c.css", "active", "is-active").await,
+            [r#"b.tsx:0:21 "is-active""#, "c.css:0:1 is-active"]
+        );
+    }
+
+    #[tokio::test]
+    async fn rename_rejects_invalid_names() {
+        let backend = workspace(&[("app.css", ".btn { }")]).await;
+        let params = RenameParams {
+            text_document_position: at(&backend, "app.css", "btn", 1).await,
+            new_name: "1btn".to_string(),
+            work_done_progress_params: Default::default(),
+        };
+        assert!(backend.rename(params).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn vocabulary_follows_stylesheet_edits() {
+        let backend = workspace(&[("app.css", ".btn { }"), ("index.html", "")]).await;
+        assert!(backend.all_classes().await.contains("btn"));
+        backend.update_document(url("app.css"), ".button { }".to_string()).await;
+        let classes = backend.all_classes().await;
+        assert!(classes.contains("button") && !classes.contains("btn"));
+        // Template edits keep the cached vocabulary
+        backend.update_document(url("index.html"), "<a></a>".to_string()).await;
+        assert!(Arc::ptr_eq(&classes, &backend.all_classes().await));
+    }
+
+    #[tokio::test]
+    async fn code_actions_suggest_replacements_for_the_warning_in_range() {
+        let html = r#"<div class="d-felx btn-lg"></div>"#;
+        let backend =
+            workspace(&[("app.css", ".d-flex { }\n.btn { }"), ("index.html", html)]).await;
+        let text = html.to_string();
+        let known = backend.all_classes().await;
+        let warnings = diagnostics::undefined_classes(&text, &Settings::default(), &known, &[]);
+        assert_eq!(warnings.len(), 2);
+        let titles = |range: Range| {
+            let backend = backend.clone();
+            let warnings = warnings.clone();
+            async move {
+                let params = CodeActionParams {
+                    text_document: TextDocumentIdentifier::new(url("index.html")),
+                    range,
+                    context: CodeActionContext { diagnostics: warnings, ..Default::default() },
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                };
+                let actions = backend.code_action(params).await.unwrap().unwrap();
+                actions
+                    .into_iter()
+                    .filter_map(|a| match a {
+                        CodeActionOrCommand::CodeAction(a) => Some(a.title),
+                        CodeActionOrCommand::Command(_) => None,
+                    })
+                    .filter(|t| t.starts_with("Replace"))
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(titles(warnings[0].range).await, ["Replace with `d-flex`"]);
+        let cursor = Position::new(0, 22);
+        assert!(titles(Range::new(cursor, cursor)).await.is_empty());
+    }
+
+    #[test]
+    fn vendored_and_linked_stylesheets() {
+        assert!(is_vendored(&url("node_modules/bootstrap/dist/css/bootstrap.css")));
+        assert!(is_vendored(&url("css/app.min.css")));
+        assert!(!is_vendored(&url("css/app.css")));
+        assert!(links_to("css/app.css?v=3", &url("public/css/app.css")));
+        assert!(links_to("../css/app.css", &url("css/app.css")));
+        assert!(!links_to("https://cdn.example.com/css/app.css", &url("css/app.css")));
+        assert!(!links_to("app.css", &url("css/my-app.css")));
+    }
+
+    #[tokio::test]
+    async fn create_actions_prefer_linked_then_last_edited_sheets() {
+        let html = r#"<link rel="stylesheet" href="css/theme.css"><p class="hero"></p>"#;
+        let backend = workspace(&[
+            ("a.css", ""),
+            ("b.css", ""),
+            ("css/theme.css", ""),
+            ("vendor/lib.css", ""),
+            ("app.min.css", ""),
+            ("index.html", html),
+        ])
+        .await;
+        *backend.last_stylesheet.write().await = Some(url("b.css"));
+        let diagnostic = Diagnostic::default();
+        let titles: Vec<String> = backend
+            .create_class_actions(&url("index.html"), "hero", &diagnostic)
+            .await
+            .into_iter()
+            .filter_map(|a| match a {
+                CodeActionOrCommand::CodeAction(a) => Some(a.title),
+                CodeActionOrCommand::Command(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "Create `.hero` in css/theme.css",
+                "Create `.hero` in b.css",
+                "Create `.hero` in a.css",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn unused_class_report_skips_vendored_sheets() {
+        let backend = workspace(&[
+            ("app.css", ".used { }\n.stale { }"),
+            ("node_modules/lib/lib.css", ".lib-widget { }"),
+            ("lib.min.css", ".min-widget { }"),
+            ("index.html", r#"<div class="used"></div>"#),
+        ])
+        .await;
+        let report = backend.unused_class_report().await;
+        let classes: Vec<&str> = report.iter().filter_map(|e| e["class"].as_str()).collect();
+        assert_eq!(classes, ["stale"]);
+    }
+
+    #[tokio::test]
+    async fn completion_items_name_their_source_and_rules() {
+        let backend = workspace(&[
+            ("b.css", "\n.hero { color: red }"),
+            ("a.scss", "@media print {\n  .hero { display: none; }\n}"),
+        ])
+        .await;
+        assert_eq!(backend.class_sources().await["hero"], "a.scss:2");
+        let item = CompletionItem {
+            data: Some(serde_json::Value::String("hero".into())),
+            ..Default::default()
+        };
+        let item = backend.completion_resolve(item).await.unwrap();
+        let Some(Documentation::MarkupContent(doc)) = item.documentation else {
+            panic!("no documentation");
+        };
+        assert_eq!(
+            doc.value,
+            "`a.scss:2`\n```css\n@media print {\n  .hero {\n    display: none;\n  }\n}\n```\
+             \n\n`b.css:2`\n```css\n.hero {\n  color: red;\n}\n```"
+        );
+    }
+
+    #[test]
+    fn detected_and_pinned_frameworks() {
+        let root = std::env::temp_dir().join(format!("css-class-lsp-load-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("tailwind.config.js"), "module.exports = { theme: {} }").unwrap();
+        let names = |frameworks: Option<&[&str]>| {
+            let settings = Settings {
+                frameworks: frameworks.map(|ids| ids.iter().map(|id| id.to_string()).collect()),
+                ..Default::default()
+            };
+            let roots = [root.clone()];
+            let loaded = load_frameworks(&roots, &settings, &["/css/bulma.min.css".into()]);
+            loaded.into_iter().map(|f| f.name).collect::<Vec<_>>()
+        };
+        assert_eq!(names(None), [frameworks::BULMA_NAME, tailwind::NAME]);
+        assert_eq!(names(Some(&["pico"])), [frameworks::PICO_NAME]);
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(names(Some(&["tailwind"])), [tailwind::NAME]);
+    }
+
+    #[tokio::test]
+    async fn closing_a_buffer_restores_the_saved_file() {
+        let dir = std::env::temp_dir().join(format!("css-class-lsp-close-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let saved = Url::from_file_path(dir.join("app.css")).unwrap();
+        std::fs::write(dir.join("app.css"), ".saved { }").unwrap();
+
+        let backend = workspace(&[("draft.css", ".draft { }")]).await;
+        backend.update_document(saved.clone(), ".unsaved { }".to_string()).await;
+        let close = |uri: Url| DidCloseTextDocumentParams {
+            text_document: TextDocumentIdentifier::new(uri),
+        };
+        backend.did_close(close(saved)).await;
+        backend.did_close(close(url("draft.css"))).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let classes = backend.all_classes().await;
+        assert!(classes.contains("saved"));
+        assert!(!classes.contains("unsaved") && !classes.contains("draft"));
+    }
+
+    #[tokio::test]
+    async fn pushed_settings_layer_over_initialization_options() {
+        let backend = workspace(&[]).await;
+        *backend.init_options.write().await =
+            serde_json::json!({ "unusedClasses": false, "frameworks": ["pico"] });
+        // The shape the Zed extension sends: settings under the section name
+        let pushed = serde_json::json!({ settings::SECTION: { "frameworks": ["bulma"] } });
+        backend
+            .did_change_configuration(DidChangeConfigurationParams { settings: pushed })
+            .await;
+        let settings = backend.current_settings().await;
+        assert_eq!(settings.frameworks, Some(vec!["bulma".to_string()]));
+        assert!(!settings.unused_classes);
+        let names: Vec<String> =
+            backend.frameworks.read().await.iter().map(|f| f.name.clone()).collect();
+        assert_eq!(names, [frameworks::BULMA_NAME]);
+    }
+
+    /// The text each completion at `needle` (moved `offset` in) would insert, sorted.
+    async fn completions(backend: &Backend, path: &str, needle: &str, offset: u32) -> Vec<String> {
+        let params = CompletionParams {
+            text_document_position: at(backend, path, needle, offset).await,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            context: None,
+        };
+        let Some(CompletionResponse::Array(items)) = backend.completion(params).await.unwrap()
+        else {
+            return Vec::new();
+        };
+        let mut texts: Vec<String> = items
+            .into_iter()
+            .filter_map(|item| match item.text_edit? {
+                CompletionTextEdit::Edit(edit) => Some(edit.new_text),
+                CompletionTextEdit::InsertAndReplace(edit) => Some(edit.new_text),
+            })
+            .collect();
+        texts.sort();
+        texts
+    }
+
+    #[tokio::test]
+    async fn object_key_completions_are_quoted_when_needed() {
+        let backend = workspace(&[
+            ("app.css", ".active { }\n.act-now { }\n.card { }"),
+            ("a.tsx", r#"const c = clsx("act", { act: on });"#),
+        ])
+        .await;
+        assert_eq!(completions(&backend, "a.tsx", "act\"", 3).await, ["act-now", "active"]);
+        assert_eq!(
+            completions(&backend, "a.tsx", "act:", 3).await,
+            [r#""act-now""#, "active"]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use serde_json::Value;
+use std::path::Path;
+
+use crate::frameworks::Framework;
+
+/// Directory, relative to a workspace root, that holds class packs.
+pub const DIR: &str = ".css-classes";
+
+/// True if `path` is a class pack: a `.json` or `.txt` file directly inside
+/// a `.css-classes` directory.
+pub fn is_pack_path(path: &Path) -> bool {
+    let in_dir = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .is_some_and(|n| n == DIR);
+    let ext = path.extension().and_then(|e| e.to_str());
+    in_dir && matches!(ext, Some("json" | "txt"))
+}
+
+/// Loads every class pack in `root/.css-classes`, in file-name order.
+pub fn load(root: &Path) -> Vec<Framework> {
+    let Ok(entries) = std::fs::read_dir(root.join(DIR)) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| is_pack_path(p))
+        .collect();
+    paths.sort();
+
+    let mut packs = Vec::new();
+    for path in paths {
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(DIR)
+            .to_string();
+        let pack = if path.extension().is_some_and(|e| e == "json") {
+            match serde_json::from_str(&text) {
+                Ok(json) => parse_json(name, &json),
+                Err(e) => {
+                    eprintln!("[css-class-lsp] invalid class pack {}: {e}", path.display());
+                    continue;
+                }
+            }
+        } else {
+            parse_text(name, &text)
+        };
+        eprintln!(
+            "[css-class-lsp] class pack {}: {} class(es)",
+            path.display(),
+            pack.classes.len()
+        );
+        packs.push(pack);
+    }
+    packs
+}
+
+/// Reads a JSON pack. Either a bare array of classes, or an object with an
+/// optional `name` and a `classes` array. Each class is a string or an object
+/// with `name` and optional `description` and `category`:
+///
+/// ```json
+/// { "name": "Design System",
+///   "classes": ["ds-stack", { "name": "ds-card", "description": "Raised surface", "category": "Layout" }] }
+/// ```
+fn parse_json(name: String, json: &Value) -> Framework {
+    let mut pack = Framework {
+        name: json
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map_or(name, |n| n.to_string()),
+        ..Default::default()
+    };
+    let entries = json
+        .get("classes")
+        .unwrap_or(json)
+        .as_array()
+        .map_or(&[][..], |a| a.as_slice());
+
+    for entry in entries {
+        let (class, fields) = match entry {
+            Value::String(class) => (class.as_str(), None),
+            Value::Object(fields) => match fields.get("name").and_then(|n| n.as_str()) {
+                Some(class) => (class, Some(fields)),
+                None => continue,
+            },
+            _ => continue,
+        };
+        let class = class.trim_start_matches('.').to_string();
+        if let Some(fields) = fields {
+            if let Some(description) = fields.get("description").and_then(|d| d.as_str()) {
+                pack.descriptions.insert(class.clone(), description.to_string());
+            }
+            if let Some(category) = fields.get("category").and_then(|c| c.as_str()) {
+                pack.categories.insert(class.clone(), category.to_string());
+            }
+        }
+        pack.classes.insert(class);
+    }
+    pack
+}
+
+/// Reads a plain-text pack: one class per line, optionally followed by a
+/// description. Blank lines and lines starting with `#` are skipped.
+fn parse_text(name: String, text: &str) -> Framework {
+    let mut pack = Framework {
+        name,
+        ..Default::default()
+    };
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (class, description) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(c, d)| (c, d.trim()));
+        let class = class.trim_start_matches('.').to_string();
+        if !description.is_empty() {
+            pack.descriptions.insert(class.clone(), description.to_string());
+        }
+        pack.classes.insert(class);
+    }
+    pack
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_packs() {
+        let json = serde_json::json!({
+            "name": "Design System",
+            "classes": [
+                "ds-stack",
+                ".ds-row",
+                { "name": "ds-card", "description": "Raised surface", "category": "Layout" },
+                { "description": "no name" },
+                42
+            ]
+        });
+        let pack = parse_json("ds".into(), &json);
+        assert_eq!(pack.name, "Design System");
+        let mut classes: Vec<&String> = pack.classes.iter().collect();
+        classes.sort();
+        assert_eq!(classes, ["ds-card", "ds-row", "ds-stack"]);
+        assert_eq!(pack.descriptions["ds-card"], "Raised surface");
+        assert_eq!(pack.categories["ds-card"], "Layout");
+
+        let bare = parse_json("brand".into(), &serde_json::json!(["brand-logo"]));
+        assert_eq!(bare.name, "brand");
+        assert!(bare.classes.contains("brand-logo"));
+    }
+
+    #[test]
+    fn text_packs() {
+        let text = "# Legacy helpers\n\n.clearfix\nhide-sm  Hidden on phones\n";
+        let pack = parse_text("legacy".into(), text);
+        assert_eq!(pack.classes.len(), 2);
+        assert!(pack.classes.contains("clearfix"));
+        assert_eq!(pack.descriptions["hide-sm"], "Hidden on phones");
+    }
+
+    #[test]
+    fn pack_paths() {
+        assert!(is_pack_path(Path::new("/ws/.css-classes/brand.json")));
+        assert!(is_pack_path(Path::new("/ws/.css-classes/legacy.txt")));
+        assert!(!is_pack_path(Path::new("/ws/.css-classes/notes.md")));
+        assert!(!is_pack_path(Path::new("/ws/.css-classes/nested/brand.json")));
+    }
+}
@@ -0,0 +1,362 @@
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::frameworks::BootstrapTheme;
+use crate::scanner;
+use crate::settings::Settings;
+
+/// The Bootstrap maps the generated utilities depend on.
+const MAPS: &[&str] = &["grid-breakpoints", "spacers", "theme-colors"];
+
+/// Bootstrap 5.3's color variables, so `$theme-colors` entries such as
+/// `"primary": $primary` or `"brand": $indigo` resolve to a value.
+static COLOR_VARIABLES: &[(&str, &str)] = &[
+    ("white", "#fff"), ("black", "#000"),
+    ("gray-100", "#f8f9fa"), ("gray-200", "#e9ecef"), ("gray-300", "#dee2e6"),
+    ("gray-400", "#ced4da"), ("gray-500", "#adb5bd"), ("gray-600", "#6c757d"),
+    ("gray-700", "#495057"), ("gray-800", "#343a40"), ("gray-900", "#212529"),
+    ("blue", "#0d6efd"), ("indigo", "#6610f2"), ("purple", "#6f42c1"), ("pink", "#d63384"),
+    ("red", "#dc3545"), ("orange", "#fd7e14"), ("yellow", "#ffc107"), ("green", "#198754"),
+    ("teal", "#20c997"), ("cyan", "#0dcaf0"),
+    ("primary", "$blue"), ("secondary", "$gray-600"), ("success", "$green"),
+    ("info", "$cyan"), ("warning", "$yellow"), ("danger", "$red"),
+    ("light", "$gray-100"), ("dark", "$gray-900"),
+];
+
+/// A Sass map: keys in declaration order, each with its unevaluated value.
+type Map = Vec<(String, String)>;
+
+/// Reads the project's overrides of `$grid-breakpoints`, `$spacers` and
+/// `$theme-colors` (and the color variables the theme colors refer to) from
+/// its `.scss` files. Returns None when no file customizes them. Literal maps,
+/// `map-merge` and `map-remove` are understood; nothing else is evaluated.
+pub fn bootstrap_theme(root: &Path, settings: &Settings) -> Option<BootstrapTheme> {
+    let mut env = Env::default();
+    let mut customized = false;
+    for path in scss_files(root, settings) {
+        let Ok(scss) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if customizes_bootstrap(&scss) {
+            eprintln!("[css-class-lsp] bootstrap sass overrides: {}", path.display());
+            customized = true;
+        }
+        for (name, value, default) in variables(&scss) {
+            env.assign(&name, &value, default);
+        }
+    }
+    customized.then(|| env.theme())
+}
+
+/// The `.scss` files under `root` that customize the Bootstrap theme, so a
+/// later edit that drops the overrides is still noticed.
+pub fn bootstrap_overrides(root: &Path, settings: &Settings) -> Vec<PathBuf> {
+    scss_files(root, settings)
+        .into_iter()
+        .filter(|path| {
+            std::fs::read_to_string(path).is_ok_and(|scss| customizes_bootstrap(&scss))
+        })
+        .collect()
+}
+
+/// True if a change to `path` can change the Bootstrap theme: a `.scss` file
+/// that sets one of the maps or theme color variables.
+pub fn is_bootstrap_override(path: &Path, content: &str) -> bool {
+    path.extension().is_some_and(|e| e == "scss") && customizes_bootstrap(content)
+}
+
+/// The workspace's `.scss` files, sorted so later files override earlier ones
+/// predictably.
+fn scss_files(root: &Path, settings: &Settings) -> Vec<PathBuf> {
+    let mut paths: Vec<_> = scanner::find_css_files(root, settings)
+        .into_iter()
+        .filter(|p| p.extension().is_some_and(|e| e == "scss"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn customizes_bootstrap(scss: &str) -> bool {
+    let re = Regex::new(
+        r"\$(?:grid-breakpoints|spacers|theme-colors|primary|secondary|success|info|warning|danger|light|dark)\s*:",
+    )
+    .unwrap();
+    re.is_match(scss)
+}
+
+/// The variables assigned so far: Bootstrap's maps evaluated, everything
+/// else kept as written.
+#[derive(Default)]
+struct Env {
+    maps: HashMap<String, Map>,
+    scalars: HashMap<String, String>,
+}
+
+impl Env {
+    fn assign(&mut self, name: &str, value: &str, default: bool) {
+        if MAPS.contains(&name) {
+            if default && self.maps.contains_key(name) {
+                return;
+            }
+            if let Some(map) = self.eval_map(value) {
+                self.maps.insert(name.to_string(), map);
+            }
+        } else if !(default && self.scalars.contains_key(name)) {
+            self.scalars.insert(name.to_string(), value.to_string());
+        }
+    }
+
+    /// Evaluates a map expression: a literal, a `$variable`, or a
+    /// `map-merge`/`map-remove` call (either spelling) over those.
+    fn eval_map(&self, expr: &str) -> Option<Map> {
+        let expr = expr.trim();
+        if let Some(name) = expr.strip_prefix('$') {
+            return self.maps.get(name).cloned().or_else(|| stock_map(name));
+        }
+        if let Some(args) = call_args(expr, &["map-merge", "map.merge"]) {
+            let mut merged = Map::new();
+            for arg in args {
+                for (key, value) in self.eval_map(arg)? {
+                    match merged.iter_mut().find(|(k, _)| *k == key) {
+                        Some(entry) => entry.1 = value,
+                        None => merged.push((key, value)),
+                    }
+                }
+            }
+            return Some(merged);
+        }
+        if let Some(args) = call_args(expr, &["map-remove", "map.remove"]) {
+            let (map, keys) = args.split_first()?;
+            let keys: Vec<String> = keys.iter().map(|k| unquote(k)).collect();
+            let mut map = self.eval_map(map)?;
+            map.retain(|(key, _)| !keys.contains(key));
+            return Some(map);
+        }
+        map_literal(expr)
+    }
+
+    /// Follows `$variable` references to a value, through the project's
+    /// assignments and then Bootstrap's own color variables.
+    fn resolve(&self, value: &str) -> String {
+        let mut value = value.trim().to_string();
+        for _ in 0..8 {
+            let Some(name) = value.strip_prefix('$') else {
+                break;
+            };
+            let next = self.scalars.get(name).cloned().or_else(|| {
+                COLOR_VARIABLES
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, v)| v.to_string())
+            });
+            match next {
+                Some(next) => value = next,
+                None => break,
+            }
+        }
+        value
+    }
+
+    fn theme(&self) -> BootstrapTheme {
+        let map = |name: &str| {
+            self.maps.get(name).cloned().or_else(|| stock_map(name)).unwrap_or_default()
+        };
+        let keys = |map: Map| map.into_iter().map(|(key, _)| key).collect();
+        BootstrapTheme {
+            breakpoints: keys(map("grid-breakpoints")),
+            spacers: keys(map("spacers")),
+            colors: map("theme-colors")
+                .into_iter()
+                .map(|(name, value)| (name, self.resolve(&value)))
+                .collect(),
+        }
+    }
+}
+
+/// Bootstrap's own value of one of [`MAPS`], for `map-merge($spacers, …)`
+/// when the project never assigned it.
+fn stock_map(name: &str) -> Option<Map> {
+    let stock = BootstrapTheme::default();
+    let keys = |keys: Vec<String>| keys.into_iter().map(|k| (k, String::new())).collect();
+    match name {
+        "grid-breakpoints" => Some(keys(stock.breakpoints)),
+        "spacers" => Some(keys(stock.spacers)),
+        // By reference, so a project's `$primary: …` still recolors `primary`.
+        "theme-colors" => Some(
+            stock.colors.into_iter().map(|(c, _)| (c.clone(), format!("${c}"))).collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Every `$name: value;` assignment in `scss`, and every `$name: value`
+/// entry of a `@use … with (…)` or `@forward … with (…)` configuration, in
+/// file order with comments stripped: (name, value without flags, whether
+/// it was `!default`).
+fn variables(scss: &str) -> Vec<(String, String, bool)> {
+    let block_re = Regex::new(r"/\*[\s\S]*?\*/").unwrap();
+    let line_re = Regex::new(r"(?m)(^|[^:])//.*$").unwrap();
+    let scss = block_re.replace_all(scss, "");
+    let mut scss = line_re.replace_all(&scss, "$1").into_owned();
+
+    let flag_re = Regex::new(r"\s*!(default|global)\b").unwrap();
+    let assignment = |name: &str, value: &str| {
+        let default = value.contains("!default");
+        let value = flag_re.replace_all(value, "").trim().to_string();
+        (name.to_string(), value, default)
+    };
+
+    // Configurations first, blanked out so their entries are not read as
+    // one assignment running up to the `;`
+    let mut found = Vec::new();
+    let with_re = Regex::new(r"@(?:use|forward)\b[^;{}]*?\bwith\s*\(").unwrap();
+    let spans: Vec<(usize, usize)> = with_re
+        .find_iter(&scss)
+        .filter_map(|m| Some((m.end() - 1, closing_paren(&scss, m.end() - 1)?)))
+        .collect();
+    for &(open, close) in &spans {
+        for entry in split_top_level(&scss[open + 1..close]) {
+            let Some((name, value)) = entry.split_once(':') else {
+                continue;
+            };
+            if let Some(name) = name.trim().strip_prefix('$') {
+                found.push((open, assignment(name, value)));
+            }
+        }
+    }
+    for &(open, close) in &spans {
+        scss.replace_range(open..=close, &" ".repeat(close - open + 1));
+    }
+
+    let var_re = Regex::new(r"\$([\w-]+)\s*:\s*([^;]+);").unwrap();
+    for c in var_re.captures_iter(&scss) {
+        found.push((c.get(0).unwrap().start(), assignment(&c[1], &c[2])));
+    }
+    found.sort_by_key(|(at, _)| *at);
+    found.into_iter().map(|(_, variable)| variable).collect()
+}
+
+/// Byte offset of the `)` matching the `(` at `open`, skipping quoted text.
+fn closing_paren(s: &str, open: usize) -> Option<usize> {
+    let (mut depth, mut quote) = (0i32, None);
+    for (i, c) in s[open..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The arguments of `expr` if it is a call to one of `functions`.
+fn call_args<'a>(expr: &'a str, functions: &[&str]) -> Option<Vec<&'a str>> {
+    let args = functions
+        .iter()
+        .find_map(|f| expr.strip_prefix(f))?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    Some(split_top_level(args))
+}
+
+/// Parses `(key: value, …)`. Keys are unquoted; values are kept as written.
+fn map_literal(expr: &str) -> Option<Map> {
+    let inner = expr.strip_prefix('(')?.strip_suffix(')')?;
+    let mut map = Map::new();
+    for entry in split_top_level(inner) {
+        let (key, value) = entry.split_once(':')?;
+        map.push((unquote(key), value.trim().to_string()));
+    }
+    Some(map)
+}
+
+/// Splits on commas outside parentheses and quotes, dropping empty pieces
+/// (a trailing comma).
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut quote, mut start) = (0i32, None, 0);
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts.into_iter().map(str::trim).filter(|p| !p.is_empty()).collect()
+}
+
+fn unquote(s: &str) -> String {
+    s.trim().trim_matches(|c| c == '"' || c == '\'').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(map: Option<Map>) -> Vec<String> {
+        map.unwrap().into_iter().map(|(key, _)| key).collect()
+    }
+
+    #[test]
+    fn maps_merge_remove_and_refer() {
+        let mut env = Env::default();
+        env.assign("spacers", "map-merge($spacers, (6: 4rem, 7: 5rem))", false);
+        assert_eq!(keys(env.eval_map("$spacers")), ["0", "1", "2", "3", "4", "5", "6", "7"]);
+        assert_eq!(
+            keys(env.eval_map("map.remove($spacers, 0, \"7\")")),
+            ["1", "2", "3", "4", "5", "6"]
+        );
+        let merged = env.eval_map("map-merge((sm: 1px, md: 2px), (md: 3px))").unwrap();
+        assert_eq!(merged, [("sm".into(), "1px".into()), ("md".into(), "3px".into())]);
+        assert!(env.eval_map("map-get($spacers, 1)").is_none());
+    }
+
+    #[test]
+    fn defaults_do_not_override_earlier_assignments() {
+        let mut env = Env::default();
+        env.assign("spacers", "(0: 0, 1: 1rem)", false);
+        env.assign("spacers", "(0: 0)", true);
+        env.assign("primary", "#f60", false);
+        env.assign("primary", "$blue", true);
+        let theme = env.theme();
+        assert_eq!(theme.spacers, ["0", "1"]);
+        let primary = theme.colors.iter().find(|(name, _)| name == "primary").unwrap();
+        assert_eq!(primary.1, "#f60");
+    }
+
+    #[test]
+    fn variables_include_use_with_configurations() {
+        let scss = "// $spacers: (0: 0);\n$brand: #f60 !default;\n\
+                    @use \"bootstrap\" with (\n  $primary: $brand,\n  \
+                    $grid-breakpoints: (xs: 0, md: 768px),\n);\n/* $danger: red; */";
+        let found = variables(scss);
+        assert_eq!(
+            found,
+            [
+                ("brand".into(), "#f60".into(), true),
+                ("primary".into(), "$brand".into(), false),
+                ("grid-breakpoints".into(), "(xs: 0, md: 768px)".into(), false),
+            ]
+        );
+        assert!(customizes_bootstrap(scss));
+        assert!(!customizes_bootstrap("$brand: #f60;"));
+    }
+}
@@ -1,60 +1,695 @@
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::LazyLock;
+use tower_lsp::lsp_types::{Color, Position, Range, TextEdit};
 use url::Url;
 use walkdir::WalkDir;
 
-const SKIP_DIRS: &[&str] = &["node_modules", ".git", ".next", "target"];
+use crate::colors;
+use crate::context::{self, LineIndex};
+use crate::settings::Settings;
 
-pub fn find_css_files(root: &Path) -> Vec<std::path::PathBuf> {
+// Compiled once: stylesheets and templates are re-parsed on every keystroke.
+static COMMENT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"/\*[\s\S]*?\*/").unwrap());
+static STRING_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?s)("(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*')"#).unwrap());
+static CLASS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.(-?[a-zA-Z_][a-zA-Z0-9_-]*)").unwrap());
+/// A class in a rule prelude, or the suffix of a Sass/Less `&-suffix`.
+static PRELUDE_CLASS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.(-?[a-zA-Z_][a-zA-Z0-9_-]*)|&(-?[a-zA-Z0-9_-]+)").unwrap());
+static TRAILING_CLASS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\.(-?[a-zA-Z_][a-zA-Z0-9_-]*)$").unwrap());
+static INTERPOLATION_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[#@]\{[^}]*\}").unwrap());
+static STYLE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?is)<style\b[^>]*>(.*?)</style>").unwrap());
+static EXTEND_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"@extend\s+([^;{}\n]+)").unwrap());
+static WORD_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"-?[a-zA-Z_][a-zA-Z0-9_-]*").unwrap());
+static LINK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)<link\b[^>]*\bhref\s*=\s*["']([^"']+)["'][^>]*>"#).unwrap()
+});
+
+/// A rule whose selector mentions at least one class.
+#[derive(Debug, Clone)]
+pub struct CssRule {
+    /// Selector as written, with SCSS/Less `&` references resolved against the parent rule.
+    pub selector: String,
+    /// Declarations directly inside the rule (nested rules excluded).
+    pub declarations: Vec<String>,
+    /// Enclosing at-rules, outermost first (e.g. `@media (min-width: 768px)`).
+    pub at_rules: Vec<String>,
+    /// Position of the selector in the file.
+    pub range: Range,
+}
+
+/// A class name as it appears in a selector.
+#[derive(Debug, Clone)]
+pub struct ClassSelector {
+    pub name: String,
+    /// Range of `.name` (or `&-suffix` for nested SCSS/Less suffix selectors).
+    pub range: Range,
+    /// Index into `Stylesheet::rules`.
+    pub rule: usize,
+    /// True when the name is built from the parent selector (`&-suffix`) and
+    /// therefore does not appear literally in the file.
+    pub from_parent: bool,
+}
+
+/// Everything extracted from one stylesheet.
+#[derive(Debug, Clone, Default)]
+pub struct Stylesheet {
+    pub classes: HashSet<String>,
+    pub rules: Vec<CssRule>,
+    pub selectors: Vec<ClassSelector>,
+    /// Classes named by Sass/Less `@extend .name;`, with the range of `.name`.
+    pub extends: Vec<(String, Range)>,
+    /// Position just past the last character, where new rules are appended.
+    pub end: Position,
+    /// Indented Sass syntax (no braces).
+    pub indented: bool,
+}
+
+impl Stylesheet {
+    /// Rules whose selector declares `class`, in file order.
+    pub fn rules_for<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'a CssRule> + 'a {
+        let mut seen = HashSet::new();
+        self.selectors
+            .iter()
+            .filter(move |s| s.name == class && seen.insert(s.rule))
+            .map(|s| &self.rules[s.rule])
+    }
+
+    /// The color each class sets, from rules whose whole selector is that one
+    /// class. `background-color` wins over `background`, then `color`; a
+    /// value that is not a plain color (`background: url(…)`) is passed over.
+    pub fn declared_colors(&self) -> Vec<(String, Color)> {
+        let mut colors = Vec::new();
+        for selector in &self.selectors {
+            let rule = &self.rules[selector.rule];
+            if rule.selector != format!(".{}", selector.name) {
+                continue;
+            }
+            let color = ["background-color", "background", "color"]
+                .iter()
+                .find_map(|prop| {
+                    rule.declarations.iter().find_map(|d| {
+                        let (name, value) = d.split_once(':')?;
+                        if !name.trim().eq_ignore_ascii_case(prop) {
+                            return None;
+                        }
+                        colors::parse_color(value)
+                    })
+                });
+            if let Some(color) = color {
+                colors.push((selector.name.clone(), color));
+            }
+        }
+        colors
+    }
+
+    /// An edit appending an empty `.class` rule at the end of the file.
+    pub fn append_rule(&self, class: &str) -> TextEdit {
+        let separator = if self.end.character == 0 { "" } else { "\n" };
+        let rule = if self.indented {
+            format!("{separator}\n.{class}\n")
+        } else {
+            format!("{separator}\n.{class} {{\n}}\n")
+        };
+        TextEdit::new(Range::new(self.end, self.end), rule)
+    }
+}
+
+/// A class name used in a template's class attribute or class helper call.
+#[derive(Debug, Clone)]
+pub struct ClassUsage {
+    pub name: String,
+    pub range: Range,
+    /// Set if written as an unquoted object key, so a renamed class that is
+    /// not a valid identifier must be wrapped in this quote.
+    pub bare_key: Option<char>,
+}
+
+/// A template expression inside a class list (`<?= ... ?>`, `{{ ... }}`,
+/// `${...}`), or a class list word that is not a plain name, which cannot be
+/// edited safely.
+#[derive(Debug, Clone)]
+pub struct DynamicValue {
+    /// The source, with the class names that are edited directly (string
+    /// literals in the expression) blanked out.
+    pub text: String,
+    pub range: Range,
+}
+
+/// Everything extracted from one HTML/PHP/JS/TSX file.
+#[derive(Debug, Clone, Default)]
+pub struct Template {
+    pub usages: Vec<ClassUsage>,
+    pub dynamic: Vec<DynamicValue>,
+    /// Every class-like word anywhere in the file. Used to decide whether a
+    /// stylesheet class is referenced at all, so names only toggled from
+    /// scripts (`classList.add("open")`) are not reported as unused.
+    pub words: HashSet<String>,
+    /// `href`s of `<link rel="stylesheet">` tags, e.g. a CDN Bootstrap.
+    pub stylesheet_links: Vec<String>,
+}
+
+pub fn find_css_files(root: &Path, settings: &Settings) -> Vec<std::path::PathBuf> {
+    find_files(root, settings, |path| settings.is_css_path(path))
+}
+
+pub fn find_template_files(root: &Path, settings: &Settings) -> Vec<std::path::PathBuf> {
+    find_files(root, settings, |path| settings.is_template_path(path))
+}
+
+fn find_files(
+    root: &Path,
+    settings: &Settings,
+    wanted: impl Fn(&Path) -> bool,
+) -> Vec<std::path::PathBuf> {
     WalkDir::new(root)
         .into_iter()
         .filter_entry(|e| {
             if e.file_type().is_dir() {
                 let name = e.file_name().to_string_lossy();
-                !SKIP_DIRS.contains(&name.as_ref())
+                !settings.skips_dir(&name)
             } else {
                 true
             }
         })
         .filter_map(|e| e.ok())
         .filter(|e| e.file_type().is_file())
-        .filter(|e| {
-            matches!(
-                e.path().extension().and_then(|s| s.to_str()),
-                Some("css" | "scss" | "sass" | "less")
-            )
-        })
+        .filter(|e| wanted(e.path()))
         .map(|e| e.into_path())
         .collect()
 }
 
 pub fn extract_classes(css: &str) -> HashSet<String> {
     // Strip block comments
-    let comment_re = Regex::new(r"/\*[\s\S]*?\*/").unwrap();
-    let without_comments = comment_re.replace_all(css, " ");
+    let without_comments = COMMENT_RE.replace_all(css, " ");
 
     // Strip string literals (single and double quoted)
-    let string_re = Regex::new(r#"(?s)("(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*')"#).unwrap();
-    let without_strings = string_re.replace_all(&without_comments, " ");
+    let without_strings = STRING_RE.replace_all(&without_comments, " ");
 
     // Extract class selectors
-    let class_re = Regex::new(r"\.(-?[a-zA-Z_][a-zA-Z0-9_-]*)").unwrap();
-    class_re
+    CLASS_RE
         .captures_iter(&without_strings)
+        .filter(|cap| !glued_to_interpolation(&without_strings, cap.get(0).unwrap().end()))
         .map(|cap| cap[1].to_string())
         .collect()
 }
 
-pub fn scan_directory(root: &Path) -> HashMap<Url, HashSet<String>> {
+/// Classes declared in a template's own `<style>` blocks, such as the
+/// scoped styles of a Vue or Svelte component.
+pub fn style_block_classes(text: &str) -> HashSet<String> {
+    STYLE_RE
+        .captures_iter(text)
+        .flat_map(|c| extract_classes(&c[1]))
+        .collect()
+}
+
+/// Parses a stylesheet into its class-bearing rules. `path` is only used to
+/// pick the indented Sass syntax for `.sass` files.
+pub fn parse_stylesheet(path: &Path, css: &str) -> Stylesheet {
+    let indented = path.extension().and_then(|s| s.to_str()) == Some("sass");
+    let mut sheet = if indented {
+        parse_indented(css)
+    } else {
+        parse_braced(css)
+    };
+    let lines = LineIndex::new(css);
+    sheet.end = lines.position(css.len());
+    sheet.indented = indented;
+    for target in EXTEND_RE.captures_iter(css).map(|c| c.get(1).unwrap()) {
+        for class in CLASS_RE.captures_iter(target.as_str()) {
+            let dot = class.get(0).unwrap();
+            let range = Range::new(
+                lines.position(target.start() + dot.start()),
+                lines.position(target.start() + dot.end()),
+            );
+            sheet.extends.push((class[1].to_string(), range));
+        }
+    }
+    // Keep the regex pass so classes only mentioned outside rule preludes
+    // (e.g. in `@extend`) still complete, as they always have.
+    sheet.classes = extract_classes(css);
+    sheet
+        .classes
+        .extend(sheet.selectors.iter().map(|s| s.name.clone()));
+    sheet
+}
+
+enum Frame {
+    AtRule(String),
+    Rule {
+        selector: String,
+        rule: Option<usize>,
+        declarations: Vec<String>,
+    },
+}
+
+struct Builder<'a> {
+    text: &'a str,
+    lines: LineIndex<'a>,
+    sheet: Stylesheet,
+}
+
+impl Builder<'_> {
+    /// Records a rule for `prelude` (at byte `start`) nested inside `parents`
+    /// (outermost first). Returns the resolved selector and the rule index if
+    /// it declares classes.
+    fn add_rule<'f>(
+        &mut self,
+        start: usize,
+        prelude: &str,
+        parents: impl Iterator<Item = &'f Frame> + Clone,
+    ) -> (String, Option<usize>) {
+        let parent = parents.clone().fold(None, |acc, f| match f {
+            Frame::Rule { selector, .. } => Some(selector.as_str()),
+            Frame::AtRule(_) => acc,
+        });
+        let selector = resolve_selector(prelude, parent);
+        let at_rules: Vec<String> = parents
+            .filter_map(|f| match f {
+                Frame::AtRule(a) => Some(a.clone()),
+                Frame::Rule { .. } => None,
+            })
+            .collect();
+
+        let masked = mask_interpolation(prelude);
+        let mut found = Vec::new();
+        for cap in PRELUDE_CLASS_RE.captures_iter(&masked) {
+            let whole = cap.get(0).unwrap();
+            if glued_to_interpolation(prelude, whole.end()) {
+                continue;
+            }
+            let range = Range::new(
+                self.lines.position(start + whole.start()),
+                self.lines.position(start + whole.end()),
+            );
+            if let Some(name) = cap.get(1) {
+                found.push((name.as_str().to_string(), range, false));
+            } else if let Some(suffix) = cap.get(2) {
+                for base in parent.into_iter().flat_map(trailing_classes) {
+                    found.push((format!("{base}{}", suffix.as_str()), range, true));
+                }
+            }
+        }
+        if found.is_empty() {
+            return (selector, None);
+        }
+
+        let index = self.sheet.rules.len();
+        let end = start + prelude.len();
+        self.sheet.rules.push(CssRule {
+            selector: selector.clone(),
+            declarations: Vec::new(),
+            at_rules,
+            range: Range::new(self.lines.position(start), self.lines.position(end)),
+        });
+        for (name, range, from_parent) in found {
+            self.sheet.selectors.push(ClassSelector {
+                name,
+                range,
+                rule: index,
+                from_parent,
+            });
+        }
+        (selector, Some(index))
+    }
+}
+
+fn parse_braced(css: &str) -> Stylesheet {
+    let mut b = Builder {
+        text: css,
+        lines: LineIndex::new(css),
+        sheet: Stylesheet::default(),
+    };
+    let bytes = css.as_bytes();
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut start = 0usize;
+    let mut parens = 0usize;
+    let mut i = 0usize;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let end = css[i + 2..].find("*/").map_or(css.len(), |e| i + 2 + e + 2);
+                if b.text[start..i].trim().is_empty() {
+                    start = end;
+                }
+                i = end;
+                continue;
+            }
+            // SCSS/Less line comments; `//` inside `url(...)` is not a comment.
+            b'/' if bytes.get(i + 1) == Some(&b'/') && parens == 0 => {
+                let end = css[i..].find('\n').map_or(css.len(), |e| i + e);
+                if b.text[start..i].trim().is_empty() {
+                    start = end;
+                }
+                i = end;
+                continue;
+            }
+            q @ (b'"' | b'\'') => {
+                i = skip_string(bytes, i, q);
+                continue;
+            }
+            b'#' if bytes.get(i + 1) == Some(&b'{') => {
+                // SCSS interpolation: not a block
+                i = css[i..].find('}').map_or(css.len(), |e| i + e + 1);
+                continue;
+            }
+            b'(' => parens += 1,
+            b')' => parens = parens.saturating_sub(1),
+            b'{' => {
+                let raw = &css[start..i];
+                let offset = raw.len() - raw.trim_start().len();
+                let prelude = raw.trim();
+                let frame = if prelude.starts_with('@') {
+                    Frame::AtRule(collapse_whitespace(prelude))
+                } else {
+                    let (selector, rule) = b.add_rule(start + offset, prelude, stack.iter());
+                    Frame::Rule {
+                        selector,
+                        rule,
+                        declarations: Vec::new(),
+                    }
+                };
+                stack.push(frame);
+                start = i + 1;
+            }
+            b';' => {
+                push_declaration(&mut stack, &css[start..i]);
+                start = i + 1;
+            }
+            b'}' => {
+                push_declaration(&mut stack, &css[start..i]);
+                if let Some(Frame::Rule {
+                    rule: Some(rule),
+                    declarations,
+                    ..
+                }) = stack.pop()
+                {
+                    b.sheet.rules[rule].declarations = declarations;
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    b.sheet
+}
+
+/// Indented Sass: a line opens a block when the next non-blank line is indented deeper.
+fn parse_indented(sass: &str) -> Stylesheet {
+    let mut b = Builder {
+        text: sass,
+        lines: LineIndex::new(sass),
+        sheet: Stylesheet::default(),
+    };
+    let mut lines: Vec<(usize, usize, &str)> = Vec::new(); // (byte offset, indent, content)
+    let mut offset = 0;
+    for line in sass.split('\n') {
+        let content = line.trim_end();
+        let trimmed = content.trim_start();
+        if !trimmed.is_empty() && !trimmed.starts_with("//") {
+            let indent = content.len() - trimmed.len();
+            lines.push((offset + indent, indent, trimmed));
+        }
+        offset += line.len() + 1;
+    }
+
+    let mut stack: Vec<(usize, Frame)> = Vec::new();
+    for (n, &(start, indent, content)) in lines.iter().enumerate() {
+        while stack.last().is_some_and(|(i, _)| *i >= indent) {
+            close_indented(&mut b.sheet, stack.pop().unwrap().1);
+        }
+        let opens = lines.get(n + 1).is_some_and(|&(_, next, _)| next > indent);
+        if !opens {
+            if let Some((_, Frame::Rule { declarations, .. })) = stack.last_mut() {
+                declarations.push(collapse_whitespace(content));
+            }
+            continue;
+        }
+        let frame = if content.starts_with('@') {
+            Frame::AtRule(collapse_whitespace(content))
+        } else {
+            let (selector, rule) = b.add_rule(start, content, stack.iter().map(|(_, f)| f));
+            Frame::Rule {
+                selector,
+                rule,
+                declarations: Vec::new(),
+            }
+        };
+        stack.push((indent, frame));
+    }
+    while let Some((_, frame)) = stack.pop() {
+        close_indented(&mut b.sheet, frame);
+    }
+    b.sheet
+}
+
+fn close_indented(sheet: &mut Stylesheet, frame: Frame) {
+    if let Frame::Rule {
+        rule: Some(rule),
+        declarations,
+        ..
+    } = frame
+    {
+        sheet.rules[rule].declarations = declarations;
+    }
+}
+
+fn push_declaration(stack: &mut [Frame], raw: &str) {
+    let decl = raw.trim();
+    if decl.is_empty() {
+        return;
+    }
+    if let Some(Frame::Rule { declarations, .. }) = stack.last_mut() {
+        declarations.push(collapse_whitespace(decl));
+    }
+}
+
+fn skip_string(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'\n' => return i,
+            c if c == quote => return i + 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Resolves SCSS/Less nesting: `&` is replaced by the parent selector,
+/// otherwise the parent is prepended as a descendant combinator.
+fn resolve_selector(prelude: &str, parent: Option<&str>) -> String {
+    let prelude = collapse_whitespace(prelude);
+    let Some(parent) = parent else {
+        return prelude;
+    };
+    let mut out = Vec::new();
+    for part in prelude.split(',').map(str::trim) {
+        for p in parent.split(',').map(str::trim) {
+            if part.contains('&') {
+                out.push(part.replace('&', p));
+            } else {
+                out.push(format!("{p} {part}"));
+            }
+        }
+    }
+    out.join(", ")
+}
+
+/// Classes a parent selector ends with, used to expand `&-suffix`.
+fn trailing_classes(parent: &str) -> Vec<String> {
+    parent
+        .split(',')
+        .filter_map(|p| TRAILING_CLASS_RE.captures(p.trim()).map(|c| c[1].to_string()))
+        .collect()
+}
+
+/// Whether a class name ending at `end` continues into `#{...}` / `@{...}`
+/// (`.col-#{$n}`), so its real name is only known once compiled.
+fn glued_to_interpolation(text: &str, end: usize) -> bool {
+    let rest = &text[end..];
+    rest.starts_with("#{") || rest.starts_with("@{")
+}
+
+/// Blanks out `#{...}` / `@{...}` interpolation so it is not read as a class.
+fn mask_interpolation(prelude: &str) -> String {
+    INTERPOLATION_RE
+        .replace_all(prelude, |c: &regex::Captures| " ".repeat(c[0].len()))
+        .into_owned()
+}
+
+/// Collects the class usages in a template: class attributes and class
+/// helper arguments.
+pub fn parse_template(text: &str, settings: &Settings) -> Template {
+    let lines = LineIndex::new(text);
+    let tokens = context::class_tokens(text, settings);
+    let mut spans: Vec<(usize, usize)> = context::class_values(text, settings)
+        .into_iter()
+        .filter(|v| {
+            !text[v.start..v.end]
+                .split_whitespace()
+                .all(context::is_class_name)
+        })
+        .map(|v| (v.start, v.end))
+        .collect();
+    spans.extend(context::class_expressions(text, settings));
+    let dynamic = spans
+        .into_iter()
+        .map(|(start, end)| {
+            let mut source = text[start..end].to_string();
+            for t in tokens.iter().filter(|t| start <= t.start && t.end <= end) {
+                source.replace_range(t.start - start..t.end - start, &" ".repeat(t.end - t.start));
+            }
+            DynamicValue {
+                text: source,
+                range: Range::new(lines.position(start), lines.position(end)),
+            }
+        })
+        .collect();
+    let usages = tokens
+        .into_iter()
+        .map(|t| ClassUsage {
+            name: t.name,
+            range: Range::new(lines.position(t.start), lines.position(t.end)),
+            bare_key: t.bare_key,
+        })
+        .collect();
+    let words = WORD_RE
+        .find_iter(text)
+        .map(|m| m.as_str().to_string())
+        .collect();
+    let stylesheet_links = LINK_RE
+        .captures_iter(text)
+        .filter(|c| c[0].to_ascii_lowercase().contains("stylesheet"))
+        .map(|c| c[1].to_string())
+        .collect();
+    Template {
+        usages,
+        dynamic,
+        words,
+        stylesheet_links,
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn scan_directory(root: &Path, settings: &Settings) -> HashMap<Url, Stylesheet> {
     let mut result = HashMap::new();
-    for path in find_css_files(root) {
+    for path in find_css_files(root, settings) {
         if let Ok(content) = std::fs::read_to_string(&path) {
-            let classes = extract_classes(&content);
+            let sheet = parse_stylesheet(&path, &content);
             if let Ok(url) = Url::from_file_path(&path) {
-                result.insert(url, classes);
+                result.insert(url, sheet);
             }
         }
     }
     result
 }
+
+pub fn scan_templates(root: &Path, settings: &Settings) -> HashMap<Url, Template> {
+    let mut result = HashMap::new();
+    for path in find_template_files(root, settings) {
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            if let Ok(url) = Url::from_file_path(&path) {
+                result.insert(url, parse_template(&content, settings));
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scss(text: &str) -> Stylesheet {
+        parse_stylesheet(Path::new("app.scss"), text)
+    }
+
+    /// The selector and declarations of every rule that declares `class`.
+    fn rules(sheet: &Stylesheet, class: &str) -> Vec<(String, Vec<String>)> {
+        sheet
+            .rules_for(class)
+            .map(|r| (r.selector.clone(), r.declarations.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn nested_rules_resolve_against_their_parent() {
+        let sheet = scss(".card { padding: 0; .title { margin: 0 } &.is-open { color: red } }");
+        assert_eq!(rules(&sheet, "card"), [(".card".into(), vec!["padding: 0".into()])]);
+        assert_eq!(rules(&sheet, "title"), [(".card .title".into(), vec!["margin: 0".into()])]);
+        assert_eq!(rules(&sheet, "is-open"), [(".card.is-open".into(), vec!["color: red".into()])]);
+    }
+
+    #[test]
+    fn ampersand_suffixes_build_class_names() {
+        let sheet = scss(".btn, .link {\n  &-primary { color: blue; }\n}");
+        let names: Vec<&str> = sheet.selectors.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["btn", "link", "btn-primary", "link-primary"]);
+        let suffix = sheet.selectors.iter().find(|s| s.name == "btn-primary").unwrap();
+        assert!(suffix.from_parent);
+        assert_eq!(suffix.range, Range::new(Position::new(1, 2), Position::new(1, 11)));
+        assert_eq!(rules(&sheet, "btn-primary")[0].0, ".btn-primary, .link-primary");
+    }
+
+    #[test]
+    fn at_rules_comments_and_interpolation() {
+        let sheet = scss(
+            "/* .old { } */\n@media (min-width: 768px) {\n  .col-#{$n} { width: 50%; }\n  \
+             .wide { width: 100%; }\n}",
+        );
+        assert!(!sheet.classes.contains("old"));
+        assert!(!sheet.classes.contains("col-"));
+        let names: Vec<&str> = sheet.selectors.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["wide"]);
+        let wide = sheet.rules_for("wide").next().unwrap();
+        assert_eq!(wide.at_rules, ["@media (min-width: 768px)"]);
+        assert_eq!(wide.range.start, Position::new(3, 2));
+    }
+
+    #[test]
+    fn indented_sass_nests_by_indentation() {
+        let sass = ".nav\n  display: flex\n  &-item\n    margin: 0\n";
+        let sheet = parse_stylesheet(Path::new("app.sass"), sass);
+        assert!(sheet.indented);
+        assert_eq!(rules(&sheet, "nav"), [(".nav".into(), vec!["display: flex".into()])]);
+        assert_eq!(rules(&sheet, "nav-item"), [(".nav-item".into(), vec!["margin: 0".into()])]);
+    }
+
+    #[test]
+    fn appended_rules_start_on_a_fresh_line() {
+        let rule = |path: &str, text: &str| {
+            let edit = parse_stylesheet(Path::new(path), text).append_rule("hero");
+            (edit.range.start, edit.new_text)
+        };
+        assert_eq!(rule("a.css", ".a { }"), (Position::new(0, 6), "\n\n.hero {\n}\n".into()));
+        assert_eq!(rule("a.css", ".a { }\n"), (Position::new(1, 0), "\n.hero {\n}\n".into()));
+        assert_eq!(rule("a.sass", ".a\n  b: c\n"), (Position::new(2, 0), "\n.hero\n".into()));
+    }
+
+    #[test]
+    fn declared_colors_skip_values_that_are_not_colors() {
+        let sheet = scss(
+            ".brand { background: url(bg.png); color: #ff0000 }\n.link:hover { color: blue }\n\
+             .muted { color: var(--muted) }\n.card .title { color: red }",
+        );
+        let colors = sheet.declared_colors();
+        assert_eq!(colors.len(), 1);
+        assert_eq!(colors[0].0, "brand");
+        assert_eq!(colors[0].1.red, 1.0);
+    }
+}
@@ -0,0 +1,206 @@
+use serde_json::Value;
+use std::path::Path;
+
+/// Section of the client's workspace configuration the server reads, and the
+/// key it may be nested under in `workspace/didChangeConfiguration`.
+pub const SECTION: &str = "css-class-lsp";
+
+const SKIP_DIRS: &[&str] = &["node_modules", ".git", ".next", "target"];
+const CSS_EXTENSIONS: &[&str] = &["css", "scss", "sass", "less"];
+const TEMPLATE_EXTENSIONS: &[&str] = &[
+    "html", "htm", "php", "js", "jsx", "ts", "tsx", "vue", "svelte",
+];
+const CLASS_ATTRIBUTES: &[&str] = &["class", "className"];
+const CLASS_FUNCTIONS: &[&str] = &[
+    "clsx", "cn", "classnames", "classNames", "cx", "cva", "twMerge", "twJoin",
+];
+const TRIGGER_CHARACTERS: &[&str] = &[
+    " ", "\"", "'", "-",
+    "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m",
+    "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", ":", "[",
+];
+
+/// Server settings. Every key is optional; a missing key keeps its default:
+///
+/// ```json
+/// { "frameworks": ["bulma"],
+///   "skipDirs": ["node_modules", ".git", "dist"],
+///   "cssExtensions": ["css", "scss", "pcss"],
+///   "templateExtensions": ["html", "php", "twig"],
+///   "classAttributes": ["class", "className", "tw"],
+///   "classFunctions": ["clsx", "cn", "tw"],
+///   "triggerCharacters": [" ", "\"", "-"],
+///   "unusedClasses": false }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// Identifiers of the bundled frameworks to offer; None to detect them
+    /// from the project.
+    pub frameworks: Option<Vec<String>>,
+    /// Directory names the workspace scan never descends into.
+    pub skip_dirs: Vec<String>,
+    /// Extensions (without the dot) of files indexed as stylesheets.
+    pub css_extensions: Vec<String>,
+    /// Extensions of files whose class attributes are indexed and checked.
+    pub template_extensions: Vec<String>,
+    /// Attributes whose value is a list of class names.
+    pub class_attributes: Vec<String>,
+    /// Functions whose string arguments and object keys are class names.
+    pub class_functions: Vec<String>,
+    /// Characters that open the completion list. Only read at startup, as
+    /// the server advertises them in its capabilities.
+    pub trigger_characters: Vec<String>,
+    /// Whether open stylesheets fade out selectors no template uses.
+    pub unused_classes: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect();
+        Settings {
+            frameworks: None,
+            skip_dirs: strings(SKIP_DIRS),
+            css_extensions: strings(CSS_EXTENSIONS),
+            template_extensions: strings(TEMPLATE_EXTENSIONS),
+            class_attributes: strings(CLASS_ATTRIBUTES),
+            class_functions: strings(CLASS_FUNCTIONS),
+            trigger_characters: strings(TRIGGER_CHARACTERS),
+            unused_classes: true,
+        }
+    }
+}
+
+impl Settings {
+    /// Overrides the settings `json` sets. Keys with a value of the wrong
+    /// type are logged and ignored.
+    pub fn update(&mut self, json: &Value) {
+        if !json.is_object() {
+            return;
+        }
+        if let Some(value) = json.get("frameworks") {
+            self.frameworks = if value.is_null() { None } else { strings(value, "frameworks") };
+        }
+        let lists = [
+            ("skipDirs", &mut self.skip_dirs),
+            ("cssExtensions", &mut self.css_extensions),
+            ("templateExtensions", &mut self.template_extensions),
+            ("classAttributes", &mut self.class_attributes),
+            ("classFunctions", &mut self.class_functions),
+            ("triggerCharacters", &mut self.trigger_characters),
+        ];
+        for (key, field) in lists {
+            if let Some(list) = json.get(key).and_then(|v| strings(v, key)) {
+                *field = list;
+            }
+        }
+        if let Some(value) = json.get("unusedClasses") {
+            match value.as_bool() {
+                Some(on) => self.unused_classes = on,
+                None => eprintln!("[css-class-lsp] setting unusedClasses must be a boolean"),
+            }
+        }
+        for extensions in [&mut self.css_extensions, &mut self.template_extensions] {
+            for ext in extensions.iter_mut() {
+                *ext = ext.trim_start_matches('.').to_string();
+            }
+        }
+    }
+
+    /// True if a change from `self` to `other` changes which files are
+    /// indexed or how they are read, so the workspace must be rescanned.
+    pub fn needs_rescan(&self, other: &Settings) -> bool {
+        self.skip_dirs != other.skip_dirs
+            || self.css_extensions != other.css_extensions
+            || self.template_extensions != other.template_extensions
+            || self.class_attributes != other.class_attributes
+            || self.class_functions != other.class_functions
+    }
+
+    pub fn is_css_path(&self, path: &Path) -> bool {
+        has_extension(path, &self.css_extensions)
+    }
+
+    pub fn is_template_path(&self, path: &Path) -> bool {
+        has_extension(path, &self.template_extensions)
+    }
+
+    pub fn skips_dir(&self, name: &str) -> bool {
+        self.skip_dirs.iter().any(|d| d == name)
+    }
+
+    /// File-watcher glob for files with one of `extensions`.
+    pub fn glob(extensions: &[String]) -> String {
+        match extensions {
+            [ext] => format!("**/*.{ext}"),
+            _ => format!("**/*.{{{}}}", extensions.join(",")),
+        }
+    }
+}
+
+/// A JSON array of strings, or None (logged) for anything else.
+fn strings(value: &Value, key: &str) -> Option<Vec<String>> {
+    let list: Option<Vec<String>> = value
+        .as_array()
+        .and_then(|a| a.iter().map(|v| v.as_str().map(str::to_string)).collect());
+    if list.is_none() {
+        eprintln!("[css-class-lsp] setting {key} must be an array of strings");
+    }
+    list
+}
+
+fn has_extension(path: &Path, extensions: &[String]) -> bool {
+    path.extension()
+        .and_then(|s| s.to_str())
+        .is_some_and(|ext| extensions.iter().any(|e| e == ext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn update_overrides_only_valid_keys() {
+        let mut settings = Settings::default();
+        settings.update(&json!({
+            "frameworks": ["bulma"],
+            "cssExtensions": [".css", "pcss"],
+            "classAttributes": "class",
+            "unusedClasses": "no",
+            "unknownKey": true
+        }));
+        assert_eq!(settings.frameworks, Some(vec!["bulma".to_string()]));
+        assert_eq!(settings.css_extensions, ["css", "pcss"]);
+        assert_eq!(settings.class_attributes, Settings::default().class_attributes);
+        assert!(settings.unused_classes);
+
+        settings.update(&json!({ "frameworks": null, "unusedClasses": false }));
+        assert_eq!(settings.frameworks, None);
+        assert!(!settings.unused_classes);
+        settings.update(&json!(["not", "an", "object"]));
+        assert!(!settings.unused_classes);
+    }
+
+    #[test]
+    fn rescans_follow_indexing_settings() {
+        let settings = Settings::default();
+        let mut other = settings.clone();
+        other.unused_classes = false;
+        other.trigger_characters.clear();
+        assert!(!settings.needs_rescan(&other));
+        other.skip_dirs.push("dist".into());
+        assert!(settings.needs_rescan(&other));
+    }
+
+    #[test]
+    fn paths_and_globs() {
+        let settings = Settings::default();
+        assert!(settings.is_css_path(Path::new("a/b.scss")));
+        assert!(settings.is_template_path(Path::new("App.vue")));
+        assert!(!settings.is_css_path(Path::new("README.md")));
+        assert!(settings.skips_dir("node_modules"));
+        assert_eq!(Settings::glob(&["css".into()]), "**/*.css");
+        assert_eq!(Settings::glob(&["css".into(), "less".into()]), "**/*.{css,less}");
+    }
+}
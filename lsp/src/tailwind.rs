@@ -0,0 +1,749 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::frameworks::Framework;
+use crate::scanner;
+use crate::settings::Settings;
+
+/// Display name for generated Tailwind utilities.
+pub const NAME: &str = "Tailwind CSS";
+
+/// Identifier of the Tailwind utilities in the `frameworks` setting.
+pub const ID: &str = "tailwind";
+
+const CONFIG_FILES: &[&str] = &[
+    "tailwind.config.js",
+    "tailwind.config.cjs",
+    "tailwind.config.mjs",
+    "tailwind.config.ts",
+];
+
+/// The parts of a Tailwind theme that shape the utility vocabulary.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Color name (`red-500`, `brand`) → CSS value; empty when the value is
+    /// not a literal (e.g. a function call in the config).
+    pub colors: Vec<(String, String)>,
+    pub spacing: Vec<String>,
+    pub screens: Vec<String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let mut colors = vec![
+            ("black".to_string(), "#000000".to_string()),
+            ("white".to_string(), "#ffffff".to_string()),
+        ];
+        for (family, values) in PALETTE {
+            for (shade, value) in SHADES.iter().zip(values.iter()) {
+                colors.push((format!("{family}-{shade}"), value.to_string()));
+            }
+        }
+        Theme {
+            colors,
+            spacing: SPACING.iter().map(|s| s.to_string()).collect(),
+            screens: SCREENS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+/// Reads the project's Tailwind theme from `tailwind.config.{js,cjs,mjs,ts}`
+/// or, for Tailwind v4, from `@theme` blocks in its stylesheets. Returns None
+/// when the project does not use Tailwind. Nothing is executed: only literal
+/// values in the config are understood.
+pub fn load_theme(root: &Path, settings: &Settings) -> Option<Theme> {
+    for name in CONFIG_FILES {
+        if let Ok(source) = std::fs::read_to_string(root.join(name)) {
+            eprintln!("[css-class-lsp] tailwind config: {name}");
+            return Some(parse_config(&source));
+        }
+    }
+
+    let mut theme = None;
+    for path in scanner::find_css_files(root, settings) {
+        let Ok(css) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if uses_tailwind(&css) {
+            apply_theme_blocks(theme.get_or_insert_with(Theme::default), &css);
+        }
+    }
+    theme
+}
+
+/// True if a change to `path` can change the Tailwind theme: a config file,
+/// or a stylesheet that imports Tailwind or declares `@theme`.
+pub fn is_config(path: &Path, content: &str, settings: &Settings) -> bool {
+    let is_config_file = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| CONFIG_FILES.contains(&n));
+    is_config_file || (settings.is_css_path(path) && uses_tailwind(content))
+}
+
+fn uses_tailwind(css: &str) -> bool {
+    let re = Regex::new(r#"@import\s+["']tailwindcss|@tailwind\s|@theme\b"#).unwrap();
+    re.is_match(css)
+}
+
+/// Builds the completion vocabulary for a theme.
+pub fn framework(theme: &Theme) -> Framework {
+    Framework {
+        name: NAME.to_string(),
+        classes: classes(theme),
+        colors: colors(theme),
+        variants: variants(theme),
+        arbitrary: ARBITRARY
+            .iter()
+            .map(|(p, example)| (p.to_string(), example.to_string()))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Every variant the theme allows: screens (and their `max-` forms) plus the
+/// built-in state, pseudo-element and media variants.
+pub fn variants(theme: &Theme) -> Vec<String> {
+    let mut v: Vec<String> = theme.screens.clone();
+    v.extend(theme.screens.iter().map(|s| format!("max-{s}")));
+    v.extend(VARIANTS.iter().map(|s| s.to_string()));
+    v
+}
+
+/// Every utility class the theme generates.
+pub fn classes(theme: &Theme) -> HashSet<String> {
+    let mut s: HashSet<String> = STATIC.iter().map(|c| c.to_string()).collect();
+
+    // ── Colors ───────────────────────────────────────────────────────────────
+    for p in COLOR_PREFIXES {
+        for c in ["inherit", "current", "transparent"] {
+            s.insert(format!("{p}-{c}"));
+        }
+        for (c, _) in &theme.colors {
+            s.insert(format!("{p}-{c}"));
+        }
+    }
+
+    // ── Spacing ──────────────────────────────────────────────────────────────
+    for p in SPACING_PREFIXES {
+        for v in &theme.spacing {
+            s.insert(format!("{p}-{v}"));
+            if NEGATABLE.contains(p) && v != "0" {
+                s.insert(format!("-{p}-{v}"));
+            }
+        }
+    }
+    for p in ["m","mx","my","mt","mr","mb","ml","ms","me",
+              "inset","inset-x","inset-y","top","right","bottom","left","start","end"] {
+        s.insert(format!("{p}-auto"));
+    }
+
+    // ── Sizing ───────────────────────────────────────────────────────────────
+    for p in ["w","h","size","min-w","min-h","max-h","basis"] {
+        for v in &theme.spacing {
+            s.insert(format!("{p}-{v}"));
+        }
+        for v in ["auto","full","min","max","fit"] {
+            s.insert(format!("{p}-{v}"));
+        }
+    }
+    for v in ["screen","svw","lvw","dvw"] { s.insert(format!("w-{v}")); }
+    for v in ["screen","svh","lvh","dvh"] { s.insert(format!("h-{v}")); }
+    for p in ["w","basis","inset","top","left","right","bottom","translate-x","translate-y"] {
+        for v in FRACTIONS {
+            s.insert(format!("{p}-{v}"));
+        }
+    }
+    for v in ["none","xs","sm","md","lg","xl","2xl","3xl","4xl","5xl","6xl","7xl",
+              "full","min","max","fit","prose"] {
+        s.insert(format!("max-w-{v}"));
+    }
+    for screen in &theme.screens {
+        s.insert(format!("max-w-screen-{screen}"));
+    }
+
+    // ── Grid lines & columns ─────────────────────────────────────────────────
+    for n in 1..=13 {
+        s.insert(format!("col-start-{n}"));
+        s.insert(format!("col-end-{n}"));
+    }
+    for n in 1..=7 {
+        s.insert(format!("row-start-{n}"));
+        s.insert(format!("row-end-{n}"));
+    }
+    for n in 1..=12 {
+        s.insert(format!("columns-{n}"));
+    }
+
+    // ── Filters ──────────────────────────────────────────────────────────────
+    for (name, values) in FILTERS {
+        for prefix in ["", "backdrop-"] {
+            for v in *values {
+                s.insert(if v.is_empty() {
+                    format!("{prefix}{name}")
+                } else {
+                    format!("{prefix}{name}-{v}")
+                });
+            }
+        }
+    }
+
+    s
+}
+
+/// Maps color utilities to the color they apply, for swatches.
+pub fn colors(theme: &Theme) -> HashMap<String, String> {
+    let mut m = HashMap::new();
+    for p in COLOR_PREFIXES {
+        for (c, value) in &theme.colors {
+            if !value.is_empty() {
+                m.insert(format!("{p}-{c}"), value.clone());
+            }
+        }
+    }
+    m
+}
+
+// ── tailwind.config.* ────────────────────────────────────────────────────────
+
+fn parse_config(source: &str) -> Theme {
+    let mut theme = Theme::default();
+    let theme_re = Regex::new(r"\btheme\s*:\s*\{").unwrap();
+    let Some(m) = theme_re.find(source) else {
+        return theme;
+    };
+    let JsValue::Object(entries) = Parser::new(&source[m.end() - 1..]).value() else {
+        return theme;
+    };
+
+    // `theme.colors` replaces the defaults, `theme.extend.colors` adds to them.
+    let replace = |theme: &mut Theme, key: &str, value: &JsValue| match key {
+        "colors" => theme.colors = flatten_colors(value),
+        "spacing" => theme.spacing = object_keys(value),
+        "screens" => theme.screens = object_keys(value),
+        _ => {}
+    };
+    for (key, value) in &entries {
+        replace(&mut theme, key, value);
+    }
+    if let Some((_, JsValue::Object(extend))) = entries.iter().find(|(k, _)| k == "extend") {
+        for (key, value) in extend {
+            match key.as_str() {
+                "colors" => theme.colors.extend(flatten_colors(value)),
+                "spacing" => theme.spacing.extend(object_keys(value)),
+                "screens" => theme.screens.extend(object_keys(value)),
+                _ => {}
+            }
+        }
+    }
+    theme
+}
+
+/// `{ brand: { DEFAULT: "#e34", light: "#f66" } }` → `brand`, `brand-light`.
+/// References to the default palette (`colors.blue`) expand to its shades.
+fn flatten_colors(value: &JsValue) -> Vec<(String, String)> {
+    fn walk(prefix: &str, value: &JsValue, out: &mut Vec<(String, String)>) {
+        match value {
+            JsValue::Str(s) => out.push((prefix.to_string(), s.clone())),
+            JsValue::Object(entries) => {
+                for (key, v) in entries {
+                    let name = match (prefix, key.as_str()) {
+                        (p, "DEFAULT") => p.to_string(),
+                        ("", k) => k.to_string(),
+                        (p, k) => format!("{p}-{k}"),
+                    };
+                    walk(&name, v, out);
+                }
+            }
+            JsValue::Other(expr) => {
+                let family = expr.trim().strip_prefix("colors.").unwrap_or("");
+                match PALETTE.iter().find(|(f, _)| *f == family) {
+                    Some((_, values)) => {
+                        for (shade, v) in SHADES.iter().zip(values.iter()) {
+                            out.push((format!("{prefix}-{shade}"), v.to_string()));
+                        }
+                    }
+                    None => out.push((prefix.to_string(), String::new())),
+                }
+            }
+        }
+    }
+    let mut out = Vec::new();
+    walk("", value, &mut out);
+    out.retain(|(name, _)| !name.is_empty());
+    out
+}
+
+fn object_keys(value: &JsValue) -> Vec<String> {
+    match value {
+        JsValue::Object(entries) => entries.iter().map(|(k, _)| k.clone()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Just enough of a JavaScript object-literal reader for Tailwind configs.
+#[derive(Debug)]
+enum JsValue {
+    Str(String),
+    Object(Vec<(String, JsValue)>),
+    /// Anything else (numbers, arrays, identifiers, calls), as source text.
+    Other(String),
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    i: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Parser { src, i: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.i).copied()
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = &self.src[self.i..];
+            let trimmed = rest.trim_start();
+            self.i += rest.len() - trimmed.len();
+            if trimmed.starts_with("//") {
+                self.i += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if trimmed.starts_with("/*") {
+                self.i += trimmed.find("*/").map_or(trimmed.len(), |e| e + 2);
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn value(&mut self) -> JsValue {
+        self.skip_trivia();
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(q @ (b'"' | b'\'' | b'`')) => JsValue::Str(self.string(q)),
+            _ => {
+                let start = self.i;
+                self.skip_expression();
+                JsValue::Other(self.src[start..self.i].trim().to_string())
+            }
+        }
+    }
+
+    fn object(&mut self) -> JsValue {
+        self.i += 1; // {
+        let mut entries = Vec::new();
+        loop {
+            self.skip_trivia();
+            match self.peek() {
+                None => break,
+                Some(b'}') => {
+                    self.i += 1;
+                    break;
+                }
+                Some(b',') => {
+                    self.i += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            let key = match self.peek() {
+                Some(q @ (b'"' | b'\'' | b'`')) => self.string(q),
+                _ => {
+                    let start = self.i;
+                    while self
+                        .peek()
+                        .is_some_and(|c| c.is_ascii_alphanumeric() || b"_$-.".contains(&c))
+                    {
+                        self.i += 1;
+                    }
+                    self.src[start..self.i].to_string()
+                }
+            };
+            self.skip_trivia();
+            if key.is_empty() || self.peek() != Some(b':') {
+                // Spread, computed key, method or shorthand: not a literal entry
+                self.skip_expression();
+                continue;
+            }
+            self.i += 1; // :
+            let value = self.value();
+            entries.push((key, value));
+        }
+        JsValue::Object(entries)
+    }
+
+    fn string(&mut self, quote: u8) -> String {
+        let start = self.i + 1;
+        self.i = start;
+        while let Some(c) = self.peek() {
+            self.i += 1;
+            match c {
+                b'\\' => self.i += 1,
+                c if c == quote => return self.src[start..self.i - 1].to_string(),
+                _ => {}
+            }
+        }
+        self.src[start..].to_string()
+    }
+
+    /// Skips to the next `,` or closing bracket at the current nesting level.
+    fn skip_expression(&mut self) {
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                b'"' | b'\'' | b'`' => {
+                    self.string(c);
+                    continue;
+                }
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' if depth == 0 => return,
+                b')' | b']' | b'}' => depth -= 1,
+                b',' if depth == 0 => return,
+                _ => {}
+            }
+            self.i += 1;
+        }
+    }
+}
+
+// ── Tailwind v4 `@theme` ─────────────────────────────────────────────────────
+
+/// Applies `--color-*`, `--spacing-*` and `--breakpoint-*` variables from
+/// every `@theme { ... }` block; `--color-*: initial` clears the defaults.
+fn apply_theme_blocks(theme: &mut Theme, css: &str) {
+    let block_re = Regex::new(r"@theme\b[^{]*\{([^}]*)\}").unwrap();
+    let var_re = Regex::new(r"--([a-zA-Z0-9_-]+?)(-\*)?\s*:\s*([^;]+);").unwrap();
+    for block in block_re.captures_iter(css) {
+        for var in var_re.captures_iter(&block[1]) {
+            let name = &var[1];
+            let value = var[3].trim().to_string();
+            if var.get(2).is_some() {
+                if value == "initial" {
+                    match name {
+                        "color" => theme.colors.clear(),
+                        "breakpoint" => theme.screens.clear(),
+                        "spacing" => theme.spacing.clear(),
+                        _ => {}
+                    }
+                }
+                continue;
+            }
+            if let Some(color) = name.strip_prefix("color-") {
+                theme.colors.push((color.to_string(), value));
+            } else if let Some(screen) = name.strip_prefix("breakpoint-") {
+                theme.screens.push(screen.to_string());
+            } else if let Some(space) = name.strip_prefix("spacing-") {
+                theme.spacing.push(space.to_string());
+            }
+        }
+    }
+}
+
+// ── Default theme (Tailwind CSS v3) ──────────────────────────────────────────
+
+static SHADES: &[&str] = &["50","100","200","300","400","500","600","700","800","900"];
+
+static PALETTE: &[(&str, [&str; 10])] = &[
+    ("slate",   ["#f8fafc", "#f1f5f9", "#e2e8f0", "#cbd5e1", "#94a3b8", "#64748b", "#475569", "#334155", "#1e293b", "#0f172a"]),
+    ("gray",    ["#f9fafb", "#f3f4f6", "#e5e7eb", "#d1d5db", "#9ca3af", "#6b7280", "#4b5563", "#374151", "#1f2937", "#111827"]),
+    ("zinc",    ["#fafafa", "#f4f4f5", "#e4e4e7", "#d4d4d8", "#a1a1aa", "#71717a", "#52525b", "#3f3f46", "#27272a", "#18181b"]),
+    ("neutral", ["#fafafa", "#f5f5f5", "#e5e5e5", "#d4d4d4", "#a3a3a3", "#737373", "#525252", "#404040", "#262626", "#171717"]),
+    ("stone",   ["#fafaf9", "#f5f5f4", "#e7e5e4", "#d6d3d1", "#a8a29e", "#78716c", "#57534e", "#44403c", "#292524", "#1c1917"]),
+    ("red",     ["#fef2f2", "#fee2e2", "#fecaca", "#fca5a5", "#f87171", "#ef4444", "#dc2626", "#b91c1c", "#991b1b", "#7f1d1d"]),
+    ("orange",  ["#fff7ed", "#ffedd5", "#fed7aa", "#fdba74", "#fb923c", "#f97316", "#ea580c", "#c2410c", "#9a3412", "#7c2d12"]),
+    ("amber",   ["#fffbeb", "#fef3c7", "#fde68a", "#fcd34d", "#fbbf24", "#f59e0b", "#d97706", "#b45309", "#92400e", "#78350f"]),
+    ("yellow",  ["#fefce8", "#fef9c3", "#fef08a", "#fde047", "#facc15", "#eab308", "#ca8a04", "#a16207", "#854d0e", "#713f12"]),
+    ("lime",    ["#f7fee7", "#ecfccb", "#d9f99d", "#bef264", "#a3e635", "#84cc16", "#65a30d", "#4d7c0f", "#3f6212", "#365314"]),
+    ("green",   ["#f0fdf4", "#dcfce7", "#bbf7d0", "#86efac", "#4ade80", "#22c55e", "#16a34a", "#15803d", "#166534", "#14532d"]),
+    ("emerald", ["#ecfdf5", "#d1fae5", "#a7f3d0", "#6ee7b7", "#34d399", "#10b981", "#059669", "#047857", "#065f46", "#064e3b"]),
+    ("teal",    ["#f0fdfa", "#ccfbf1", "#99f6e4", "#5eead4", "#2dd4bf", "#14b8a6", "#0d9488", "#0f766e", "#115e59", "#134e4a"]),
+    ("cyan",    ["#ecfeff", "#cffafe", "#a5f3fc", "#67e8f9", "#22d3ee", "#06b6d4", "#0891b2", "#0e7490", "#155e75", "#164e63"]),
+    ("sky",     ["#f0f9ff", "#e0f2fe", "#bae6fd", "#7dd3fc", "#38bdf8", "#0ea5e9", "#0284c7", "#0369a1", "#075985", "#0c4a6e"]),
+    ("blue",    ["#eff6ff", "#dbeafe", "#bfdbfe", "#93c5fd", "#60a5fa", "#3b82f6", "#2563eb", "#1d4ed8", "#1e40af", "#1e3a8a"]),
+    ("indigo",  ["#eef2ff", "#e0e7ff", "#c7d2fe", "#a5b4fc", "#818cf8", "#6366f1", "#4f46e5", "#4338ca", "#3730a3", "#312e81"]),
+    ("violet",  ["#f5f3ff", "#ede9fe", "#ddd6fe", "#c4b5fd", "#a78bfa", "#8b5cf6", "#7c3aed", "#6d28d9", "#5b21b6", "#4c1d95"]),
+    ("purple",  ["#faf5ff", "#f3e8ff", "#e9d5ff", "#d8b4fe", "#c084fc", "#a855f7", "#9333ea", "#7e22ce", "#6b21a8", "#581c87"]),
+    ("fuchsia", ["#fdf4ff", "#fae8ff", "#f5d0fe", "#f0abfc", "#e879f9", "#d946ef", "#c026d3", "#a21caf", "#86198f", "#701a75"]),
+    ("pink",    ["#fdf2f8", "#fce7f3", "#fbcfe8", "#f9a8d4", "#f472b6", "#ec4899", "#db2777", "#be185d", "#9d174d", "#831843"]),
+    ("rose",    ["#fff1f2", "#ffe4e6", "#fecdd3", "#fda4af", "#fb7185", "#f43f5e", "#e11d48", "#be123c", "#9f1239", "#881337"]),
+];
+
+static SPACING: &[&str] = &[
+    "0","px","0.5","1","1.5","2","2.5","3","3.5","4","5","6","7","8","9","10","11","12",
+    "14","16","20","24","28","32","36","40","44","48","52","56","60","64","72","80","96",
+];
+
+static SCREENS: &[&str] = &["sm","md","lg","xl","2xl"];
+
+static FRACTIONS: &[&str] = &[
+    "1/2","1/3","2/3","1/4","2/4","3/4","1/5","2/5","3/5","4/5",
+    "1/6","2/6","3/6","4/6","5/6","1/12","2/12","3/12","4/12","5/12","6/12",
+    "7/12","8/12","9/12","10/12","11/12",
+];
+
+static COLOR_PREFIXES: &[&str] = &[
+    "bg","text","border","border-x","border-y","border-t","border-r","border-b","border-l",
+    "border-s","border-e","ring","ring-offset","outline","divide","fill","stroke",
+    "from","via","to","decoration","placeholder","accent","caret","shadow",
+];
+
+static SPACING_PREFIXES: &[&str] = &[
+    "p","px","py","pt","pr","pb","pl","ps","pe",
+    "m","mx","my","mt","mr","mb","ml","ms","me",
+    "gap","gap-x","gap-y","space-x","space-y",
+    "inset","inset-x","inset-y","top","right","bottom","left","start","end",
+    "translate-x","translate-y","scroll-m","scroll-p","indent",
+    "border-spacing","border-spacing-x","border-spacing-y",
+];
+
+static NEGATABLE: &[&str] = &[
+    "m","mx","my","mt","mr","mb","ml","ms","me","space-x","space-y",
+    "inset","inset-x","inset-y","top","right","bottom","left","start","end",
+    "translate-x","translate-y","scroll-m","indent",
+];
+
+static VARIANTS: &[&str] = &[
+    "hover","focus","focus-within","focus-visible","active","visited","target","disabled","enabled",
+    "checked","indeterminate","required","invalid","valid","read-only","empty","open",
+    "first","last","only","odd","even","first-of-type","last-of-type",
+    "group-hover","group-focus","group-active","peer-hover","peer-focus","peer-checked","peer-invalid",
+    "before","after","placeholder","file","marker","selection","first-line","first-letter","backdrop",
+    "dark","motion-safe","motion-reduce","print","portrait","landscape","contrast-more","rtl","ltr",
+];
+
+/// Utilities that accept an arbitrary `[value]`, with the value offered as a template.
+/// One entry per prefix, as completion keys on it (`text-[` offers a color).
+static ARBITRARY: &[(&str, &str)] = &[
+    ("bg","#000000"),("text","#000000"),("border","#000000"),("ring","#000000"),
+    ("fill","#000000"),("stroke","#000000"),("from","#000000"),("via","#000000"),("to","#000000"),
+    ("p","1rem"),("px","1rem"),("py","1rem"),("pt","1rem"),("pr","1rem"),("pb","1rem"),("pl","1rem"),
+    ("m","1rem"),("mx","1rem"),("my","1rem"),("mt","1rem"),("mr","1rem"),("mb","1rem"),("ml","1rem"),
+    ("gap","1rem"),("inset","0"),("top","0"),("right","0"),("bottom","0"),("left","0"),
+    ("w","100px"),("h","100px"),("size","100px"),("min-w","100px"),("min-h","100px"),
+    ("max-w","100px"),("max-h","100px"),("basis","100px"),
+    ("leading","1.5"),("tracking","0.05em"),("rounded","4px"),
+    ("shadow","0_1px_2px_rgba(0,0,0,0.05)"),("grid-cols","repeat(3,minmax(0,1fr))"),
+    ("z","100"),("opacity","0.5"),("duration","250ms"),("translate-x","10px"),("translate-y","10px"),
+    ("rotate","17deg"),("aspect","4/3"),("content","''"),
+];
+
+/// Filter functions and their scales, each also offered as `backdrop-*`.
+/// An empty value is the bare utility (`grayscale`).
+static FILTERS: &[(&str, &[&str])] = &[
+    ("blur", &["none","sm","","md","lg","xl","2xl","3xl"]),
+    ("brightness", &["0","50","75","90","95","100","105","110","125","150","200"]),
+    ("contrast", &["0","50","75","100","125","150","200"]),
+    ("grayscale", &["0",""]),
+    ("hue-rotate", &["0","15","30","60","90","180"]),
+    ("invert", &["0",""]),
+    ("saturate", &["0","50","100","150","200"]),
+    ("sepia", &["0",""]),
+];
+
+// ── Static utilities ─────────────────────────────────────────────────────────
+static STATIC: &[&str] = &[
+    // Layout
+    "container","block","inline-block","inline","flex","inline-flex","grid","inline-grid",
+    "table","table-row","table-cell","contents","flow-root","list-item","hidden",
+    "inline-table","table-caption","table-column","table-column-group",
+    "table-header-group","table-row-group","table-footer-group",
+    "static","fixed","absolute","relative","sticky","visible","invisible","collapse",
+    "float-left","float-right","float-start","float-end","float-none",
+    "clear-left","clear-right","clear-both","clear-none",
+    "isolate","isolation-auto","box-border","box-content",
+    "box-decoration-clone","box-decoration-slice","columns-auto",
+    "overflow-auto","overflow-hidden","overflow-clip","overflow-visible","overflow-scroll",
+    "overflow-x-auto","overflow-x-hidden","overflow-x-scroll","overflow-y-auto","overflow-y-hidden","overflow-y-scroll",
+    "object-contain","object-cover","object-fill","object-none","object-scale-down","object-center",
+    "object-top","object-bottom","object-left","object-right",
+    "overscroll-auto","overscroll-contain","overscroll-none",
+    "z-0","z-10","z-20","z-30","z-40","z-50","z-auto",
+    "aspect-auto","aspect-square","aspect-video",
+    // Flexbox & grid
+    "flex-row","flex-row-reverse","flex-col","flex-col-reverse",
+    "flex-wrap","flex-wrap-reverse","flex-nowrap",
+    "flex-1","flex-auto","flex-initial","flex-none",
+    "grow","grow-0","shrink","shrink-0","flex-grow","flex-grow-0","flex-shrink","flex-shrink-0",
+    "order-first","order-last","order-none",
+    "order-1","order-2","order-3","order-4","order-5","order-6",
+    "order-7","order-8","order-9","order-10","order-11","order-12",
+    "grid-cols-1","grid-cols-2","grid-cols-3","grid-cols-4","grid-cols-5","grid-cols-6",
+    "grid-cols-7","grid-cols-8","grid-cols-9","grid-cols-10","grid-cols-11","grid-cols-12","grid-cols-none",
+    "grid-rows-1","grid-rows-2","grid-rows-3","grid-rows-4","grid-rows-5","grid-rows-6","grid-rows-none",
+    "col-auto","col-span-1","col-span-2","col-span-3","col-span-4","col-span-5","col-span-6",
+    "col-span-7","col-span-8","col-span-9","col-span-10","col-span-11","col-span-12","col-span-full",
+    "row-auto","row-span-1","row-span-2","row-span-3","row-span-4","row-span-5","row-span-6","row-span-full",
+    "col-start-auto","col-end-auto","row-start-auto","row-end-auto",
+    "auto-cols-auto","auto-cols-min","auto-cols-max","auto-cols-fr",
+    "auto-rows-auto","auto-rows-min","auto-rows-max","auto-rows-fr",
+    "grid-flow-row","grid-flow-col","grid-flow-dense","grid-flow-row-dense","grid-flow-col-dense",
+    "justify-normal","justify-start","justify-end","justify-center","justify-between",
+    "justify-around","justify-evenly","justify-stretch",
+    "justify-items-start","justify-items-end","justify-items-center","justify-items-stretch",
+    "justify-self-auto","justify-self-start","justify-self-end","justify-self-center","justify-self-stretch",
+    "content-start","content-end","content-center","content-between","content-around","content-evenly",
+    "items-start","items-end","items-center","items-baseline","items-stretch",
+    "self-auto","self-start","self-end","self-center","self-stretch","self-baseline",
+    "place-content-center","place-content-start","place-content-end","place-content-between",
+    "place-content-around","place-content-evenly","place-content-stretch",
+    "place-items-center","place-items-start","place-items-end","place-items-stretch","place-items-baseline",
+    "place-self-center","place-self-auto","place-self-start","place-self-end","place-self-stretch",
+    // Typography
+    "text-xs","text-sm","text-base","text-lg","text-xl","text-2xl","text-3xl","text-4xl",
+    "text-5xl","text-6xl","text-7xl","text-8xl","text-9xl",
+    "font-sans","font-serif","font-mono",
+    "font-thin","font-extralight","font-light","font-normal","font-medium",
+    "font-semibold","font-bold","font-extrabold","font-black",
+    "italic","not-italic","antialiased","subpixel-antialiased",
+    "tracking-tighter","tracking-tight","tracking-normal","tracking-wide","tracking-wider","tracking-widest",
+    "leading-none","leading-tight","leading-snug","leading-normal","leading-relaxed","leading-loose",
+    "leading-3","leading-4","leading-5","leading-6","leading-7","leading-8","leading-9","leading-10",
+    "text-left","text-center","text-right","text-justify","text-start","text-end",
+    "underline","overline","line-through","no-underline",
+    "uppercase","lowercase","capitalize","normal-case",
+    "truncate","text-ellipsis","text-clip","text-wrap","text-nowrap","text-balance","text-pretty",
+    "line-clamp-1","line-clamp-2","line-clamp-3","line-clamp-4","line-clamp-5","line-clamp-6","line-clamp-none",
+    "decoration-solid","decoration-double","decoration-dotted","decoration-dashed","decoration-wavy",
+    "decoration-auto","decoration-from-font","decoration-0","decoration-1","decoration-2","decoration-4",
+    "underline-offset-auto","underline-offset-0","underline-offset-1","underline-offset-2","underline-offset-4",
+    "normal-nums","ordinal","slashed-zero","lining-nums","oldstyle-nums","proportional-nums","tabular-nums",
+    "diagonal-fractions","stacked-fractions","hyphens-none","hyphens-manual","hyphens-auto","content-none",
+    "whitespace-normal","whitespace-nowrap","whitespace-pre","whitespace-pre-line","whitespace-pre-wrap",
+    "whitespace-break-spaces",
+    "break-normal","break-words","break-all","break-keep",
+    "list-none","list-disc","list-decimal","list-inside","list-outside",
+    "align-baseline","align-top","align-middle","align-bottom","align-text-top","align-text-bottom",
+    // Tables
+    "table-auto","table-fixed","border-collapse","border-separate","caption-top","caption-bottom",
+    // Borders & effects
+    "rounded-none","rounded-sm","rounded","rounded-md","rounded-lg","rounded-xl",
+    "rounded-2xl","rounded-3xl","rounded-full",
+    "rounded-t-lg","rounded-r-lg","rounded-b-lg","rounded-l-lg",
+    "border","border-0","border-2","border-4","border-8",
+    "border-t","border-r","border-b","border-l","border-x","border-y",
+    "border-solid","border-dashed","border-dotted","border-double","border-hidden","border-none",
+    "divide-x","divide-y","divide-solid","divide-dashed","divide-dotted","divide-double","divide-none",
+    "divide-x-0","divide-x-2","divide-x-4","divide-x-8","divide-y-0","divide-y-2","divide-y-4","divide-y-8",
+    "divide-x-reverse","divide-y-reverse","space-x-reverse","space-y-reverse",
+    "ring","ring-0","ring-1","ring-2","ring-4","ring-8","ring-inset",
+    "ring-offset-0","ring-offset-1","ring-offset-2","ring-offset-4","ring-offset-8",
+    "outline-none","outline","outline-dashed","outline-dotted","outline-0","outline-1","outline-2","outline-4",
+    "outline-8","outline-offset-0","outline-offset-1","outline-offset-2","outline-offset-4","outline-offset-8",
+    "shadow-sm","shadow","shadow-md","shadow-lg","shadow-xl","shadow-2xl","shadow-inner","shadow-none",
+    "opacity-0","opacity-5","opacity-10","opacity-20","opacity-25","opacity-30","opacity-40",
+    "opacity-50","opacity-60","opacity-70","opacity-75","opacity-80","opacity-90","opacity-95","opacity-100",
+    "mix-blend-normal","mix-blend-multiply","mix-blend-screen","mix-blend-overlay","mix-blend-darken",
+    "mix-blend-lighten","mix-blend-difference","mix-blend-luminosity",
+    // Filters (the scales are in FILTERS)
+    "filter","filter-none","backdrop-filter","backdrop-filter-none",
+    "drop-shadow-sm","drop-shadow","drop-shadow-md","drop-shadow-lg","drop-shadow-xl","drop-shadow-2xl",
+    "drop-shadow-none","backdrop-opacity-0","backdrop-opacity-5","backdrop-opacity-10","backdrop-opacity-20",
+    "backdrop-opacity-25","backdrop-opacity-50","backdrop-opacity-75","backdrop-opacity-100",
+    "bg-fixed","bg-local","bg-scroll","bg-cover","bg-contain","bg-center","bg-no-repeat","bg-repeat",
+    "bg-gradient-to-t","bg-gradient-to-tr","bg-gradient-to-r","bg-gradient-to-br",
+    "bg-gradient-to-b","bg-gradient-to-bl","bg-gradient-to-l","bg-gradient-to-tl",
+    // Interactivity & transitions
+    "cursor-auto","cursor-default","cursor-pointer","cursor-wait","cursor-text","cursor-move",
+    "cursor-not-allowed","cursor-help","cursor-grab","cursor-grabbing","cursor-crosshair",
+    "cursor-zoom-in","cursor-zoom-out","cursor-none","pointer-events-none","pointer-events-auto",
+    "appearance-none","appearance-auto","group","peer",
+    "scroll-auto","scroll-smooth","snap-start","snap-end","snap-center","snap-align-none",
+    "snap-normal","snap-always","snap-none","snap-x","snap-y","snap-both","snap-mandatory","snap-proximity",
+    "touch-auto","touch-none","touch-pan-x","touch-pan-y","touch-manipulation",
+    "will-change-auto","will-change-scroll","will-change-contents","will-change-transform",
+    "select-none","select-text","select-all","select-auto","resize","resize-none","resize-x","resize-y",
+    "transition","transition-all","transition-colors","transition-opacity","transition-shadow",
+    "transition-transform","transition-none",
+    "duration-0","duration-75","duration-100","duration-150","duration-200","duration-300","duration-500","duration-700","duration-1000",
+    "delay-0","delay-75","delay-100","delay-150","delay-200","delay-300","delay-500","delay-700","delay-1000",
+    "ease-linear","ease-in","ease-out","ease-in-out",
+    "animate-none","animate-spin","animate-ping","animate-pulse","animate-bounce",
+    "scale-0","scale-50","scale-75","scale-90","scale-95","scale-100","scale-105","scale-110","scale-125","scale-150",
+    "rotate-0","rotate-1","rotate-2","rotate-3","rotate-6","rotate-12","rotate-45","rotate-90","rotate-180",
+    "skew-x-0","skew-x-1","skew-x-2","skew-x-3","skew-x-6","skew-x-12",
+    "skew-y-0","skew-y-1","skew-y-2","skew-y-3","skew-y-6","skew-y-12",
+    "origin-center","origin-top","origin-top-right","origin-right","origin-bottom-right",
+    "origin-bottom","origin-bottom-left","origin-left","origin-top-left",
+    "transform","transform-none","transform-gpu","transform-cpu",
+    "sr-only","not-sr-only",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color<'a>(theme: &'a Theme, name: &str) -> Option<&'a str> {
+        theme.colors.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn config_replaces_and_extends_the_theme() {
+        let theme = parse_config(
+            r##"module.exports = {
+  content: ["./src/**/*.html"],
+  theme: {
+    screens: { tablet: "640px", desktop: "1280px" },
+    extend: {
+      colors: { brand: { DEFAULT: "#e34", light: "#f66" }, sky: colors.sky, accent: tint() },
+      spacing: { "128": "32rem" },
+    },
+  },
+};"##,
+        );
+        assert_eq!(theme.screens, ["tablet", "desktop"]);
+        assert_eq!(color(&theme, "brand"), Some("#e34"));
+        assert_eq!(color(&theme, "brand-light"), Some("#f66"));
+        assert_eq!(color(&theme, "sky-500"), Some("#0ea5e9"));
+        assert_eq!(color(&theme, "accent"), Some(""));
+        assert_eq!(color(&theme, "red-500"), Some("#ef4444"));
+        assert!(theme.spacing.contains(&"128".to_string()));
+        assert!(theme.spacing.contains(&"4".to_string()));
+    }
+
+    #[test]
+    fn config_colors_replace_the_palette() {
+        let theme = parse_config(r#"export default { theme: { colors: { ink: '#111' } } }"#);
+        assert_eq!(theme.colors, [("ink".to_string(), "#111".to_string())]);
+        assert_eq!(parse_config("module.exports = {}").colors.len(), Theme::default().colors.len());
+    }
+
+    #[test]
+    fn v4_theme_blocks() {
+        let mut theme = Theme::default();
+        apply_theme_blocks(
+            &mut theme,
+            "@import \"tailwindcss\";\n@theme {\n  --color-*: initial;\n  --color-brand: #e34;\n  \
+             --breakpoint-3xl: 1920px;\n}",
+        );
+        assert_eq!(theme.colors, [("brand".to_string(), "#e34".to_string())]);
+        assert!(theme.screens.contains(&"3xl".to_string()));
+    }
+
+    #[test]
+    fn generated_utilities() {
+        let theme = parse_config("theme: { extend: { colors: { brand: '#e34' } } }");
+        let classes = classes(&theme);
+        for class in [
+            "bg-brand", "-mt-4", "w-1/2", "max-w-screen-md", "group", "peer", "flex-shrink-0",
+            "flex-grow", "appearance-none", "origin-top-left", "col-start-13", "row-end-7",
+            "table-auto", "border-collapse", "border-spacing-2", "line-clamp-3", "grayscale",
+            "brightness-110", "backdrop-blur-sm", "backdrop-grayscale-0", "drop-shadow-md",
+        ] {
+            assert!(classes.contains(class), "{class}");
+        }
+        assert!(!classes.contains("col-start-14") && !classes.contains("-mt-0"));
+        assert_eq!(colors(&theme)["text-brand"], "#e34");
+    }
+
+    #[test]
+    fn one_arbitrary_template_per_prefix() {
+        let mut seen = HashSet::new();
+        for (prefix, _) in ARBITRARY {
+            assert!(seen.insert(prefix), "{prefix} is listed twice");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use zed_extension_api::{
-    self as zed, Architecture, DownloadedFileType, LanguageServerId, Os, Result,
+    self as zed, serde_json, settings::LspSettings, Architecture, DownloadedFileType,
+    LanguageServerId, Os, Result,
 };
 
 const BINARY_NAME: &str = "css-class-lsp";
@@ -20,13 +21,47 @@ impl zed::Extension for CssClassAutocomplete {
         language_server_id: &LanguageServerId,
         worktree: &zed::Worktree,
     ) -> Result<zed::Command> {
-        let binary = self.binary_path(language_server_id, worktree)?;
+        // "lsp": { "css-class-lsp": { "binary": { "path": ..., "arguments": [...], "env": {...} } } }
+        let binary = LspSettings::for_worktree(language_server_id.as_ref(), worktree)
+            .ok()
+            .and_then(|settings| settings.binary);
+        let (path, args, env) = match binary {
+            Some(binary) => (binary.path, binary.arguments, binary.env),
+            None => (None, None, None),
+        };
+        let command = match path {
+            Some(path) => path,
+            None => self.binary_path(language_server_id, worktree)?,
+        };
         Ok(zed::Command {
-            command: binary,
-            args: vec![],
-            env: vec![],
+            command,
+            args: args.unwrap_or_default(),
+            env: env.map(|env| env.into_iter().collect()).unwrap_or_default(),
         })
     }
+
+    fn language_server_initialization_options(
+        &mut self,
+        language_server_id: &LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        Ok(LspSettings::for_worktree(language_server_id.as_ref(), worktree)
+            .ok()
+            .and_then(|settings| settings.initialization_options))
+    }
+
+    fn language_server_workspace_configuration(
+        &mut self,
+        language_server_id: &LanguageServerId,
+        worktree: &zed::Worktree,
+    ) -> Result<Option<serde_json::Value>> {
+        // The server asks for, and accepts pushes under, its own section name
+        let settings = LspSettings::for_worktree(language_server_id.as_ref(), worktree)
+            .ok()
+            .and_then(|settings| settings.settings)
+            .unwrap_or_else(|| serde_json::json!({}));
+        Ok(Some(serde_json::json!({ language_server_id.as_ref(): settings })))
+    }
 }
 
 impl CssClassAutocomplete {
@@ -43,13 +78,13 @@ impl CssClassAutocomplete {
         // 2. ~/.cargo/bin (manual cargo install)
         let home = std::env::var("HOME").unwrap_or_default();
         let cargo_bin = format!("{home}/.cargo/bin/{BINARY_NAME}");
-        if std::fs::metadata(&cargo_bin).map_or(false, |m| m.is_file()) {
+        if std::fs::metadata(&cargo_bin).is_ok_and(|m| m.is_file()) {
             return Ok(cargo_bin);
         }
 
         // 3. Already downloaded in a previous session
         if let Some(path) = &self.cached_binary_path {
-            if std::fs::metadata(path).map_or(false, |m| m.is_file()) {
+            if std::fs::metadata(path).is_ok_and(|m| m.is_file()) {
                 return Ok(path.clone());
             }
         }